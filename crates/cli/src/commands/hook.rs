@@ -1,7 +1,8 @@
 use anyhow::Result;
 use clap::Subcommand;
 use opencode_mem_core::{
-    ObservationHookRequest, SessionInitHookRequest, SummarizeHookRequest, sanitize_input,
+    CommitHookRequest, ObservationHookRequest, SessionInitHookRequest, SummarizeHookRequest,
+    sanitize_input,
 };
 use std::io::{IsTerminal, Read};
 
@@ -45,6 +46,25 @@ pub(crate) enum HookCommands {
         #[arg(long, default_value = "http://127.0.0.1:37777")]
         endpoint: String,
     },
+    /// Record a git commit; intended to run from a `post-commit` hook.
+    Commit {
+        #[arg(long)]
+        sha: Option<String>,
+        #[arg(short, long)]
+        message: Option<String>,
+        #[arg(
+            short,
+            long = "file",
+            help = "Changed file path (repeatable); stdin lines are used when omitted"
+        )]
+        files: Vec<String>,
+        #[arg(short, long)]
+        project: Option<String>,
+        #[arg(long)]
+        session_id: Option<String>,
+        #[arg(long, default_value = "http://127.0.0.1:37777")]
+        endpoint: String,
+    },
 }
 
 fn get_project_from_stdin() -> Result<Option<String>> {
@@ -113,7 +133,78 @@ fn build_summarize_request(
     Ok(SummarizeHookRequest::new(content_session_id, session_id))
 }
 
+/// Builds a commit request from args, falling back to stdin.
+///
+/// Stdin is either a JSON object (`sha`, `message`, `files`, `project`,
+/// `sessionId`) or plain text with one changed path per line, as produced by
+/// `git diff-tree --no-commit-id --name-only -r HEAD`. Args take precedence.
+fn build_commit_request(
+    sha: Option<String>,
+    message: Option<String>,
+    files: Vec<String>,
+    project: Option<String>,
+    session_id: Option<String>,
+) -> Result<CommitHookRequest> {
+    let mut input = String::new();
+    if !std::io::stdin().is_terminal() {
+        std::io::stdin().read_to_string(&mut input)?;
+    }
+    let from_stdin = parse_commit_stdin(&input)?;
+
+    let sha = sha
+        .or(from_stdin.sha)
+        .ok_or_else(|| anyhow::anyhow!("Commit SHA required: use --sha or pipe JSON with 'sha'"))?;
+    let message = message.or(from_stdin.message).ok_or_else(|| {
+        anyhow::anyhow!("Commit message required: use --message or pipe JSON with 'message'")
+    })?;
+    let files = if files.is_empty() {
+        from_stdin.files
+    } else {
+        files
+    };
+    Ok(CommitHookRequest::new(
+        sha,
+        sanitize_input(&message),
+        files,
+        project.or(from_stdin.project),
+        session_id.or(from_stdin.session_id),
+    ))
+}
+
+#[derive(Default, serde::Deserialize)]
+struct CommitStdin {
+    sha: Option<String>,
+    message: Option<String>,
+    #[serde(default)]
+    files: Vec<String>,
+    project: Option<String>,
+    #[serde(rename = "sessionId")]
+    session_id: Option<String>,
+}
+
+fn parse_commit_stdin(input: &str) -> Result<CommitStdin> {
+    let trimmed = input.trim();
+    if trimmed.starts_with('{') {
+        return serde_json::from_str(trimmed)
+            .map_err(|e| anyhow::anyhow!("Failed to parse JSON from stdin: {}", e));
+    }
+    Ok(CommitStdin {
+        files: trimmed
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect(),
+        ..CommitStdin::default()
+    })
+}
+
 pub(crate) async fn run(cmd: HookCommands) -> Result<()> {
+    // Hooks sanitize client-side without loading the full `AppConfig`.
+    opencode_mem_core::init_content_filter_config(opencode_mem_core::parse_bool_env_with_default(
+        "OPENCODE_MEM_STRIP_ANSI",
+        true,
+    ));
     let client = reqwest::Client::new();
 
     match cmd {
@@ -175,6 +266,20 @@ pub(crate) async fn run(cmd: HookCommands) -> Result<()> {
             let body: serde_json::Value = resp.json().await?;
             println!("{}", serde_json::to_string_pretty(&body)?);
         }
+        HookCommands::Commit {
+            sha,
+            message,
+            files,
+            project,
+            session_id,
+            endpoint,
+        } => {
+            let req = build_commit_request(sha, message, files, project, session_id)?;
+            let url = format!("{endpoint}/api/events/commit");
+            let resp = client.post(&url).json(&req).send().await?;
+            let body: serde_json::Value = resp.json().await?;
+            println!("{}", serde_json::to_string_pretty(&body)?);
+        }
     }
 
     Ok(())
@@ -0,0 +1,57 @@
+//! Bulk export/import of the global knowledge layer as JSON.
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use opencode_mem_core::{AppConfig, KnowledgeExportEntry};
+use opencode_mem_embeddings::LazyEmbeddingService;
+use opencode_mem_service::KnowledgeService;
+use opencode_mem_storage::traits::KnowledgeStore;
+use std::sync::Arc;
+
+#[derive(Subcommand)]
+pub(crate) enum KnowledgeCommands {
+    Export {
+        #[arg(short, long, help = "Output file (defaults to stdout)")]
+        output: Option<String>,
+    },
+    Import {
+        file: String,
+    },
+}
+
+pub(crate) async fn run(cmd: KnowledgeCommands) -> Result<()> {
+    let storage = crate::create_storage_from_env().await?;
+
+    match cmd {
+        KnowledgeCommands::Export { output } => {
+            let entries = storage.export_knowledge().await?;
+            let json = serde_json::to_string_pretty(&entries)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, json)
+                        .with_context(|| format!("Failed to write file: {path}"))?;
+                    eprintln!("Exported {} knowledge entries to {path}", entries.len());
+                }
+                None => println!("{json}"),
+            }
+        }
+        KnowledgeCommands::Import { file } => {
+            let content = std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read file: {file}"))?;
+            let entries: Vec<KnowledgeExportEntry> = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse knowledge entries from {file}"))?;
+            let total = entries.len();
+            let config = AppConfig::from_env()?;
+            let embeddings = (!config.disable_embeddings)
+                .then(|| Arc::new(LazyEmbeddingService::new(config.embedding_threads)));
+            let knowledge = KnowledgeService::new(Arc::new(storage), embeddings);
+            let result = knowledge.import_knowledge(entries).await?;
+            println!(
+                "Imported {total} entries: {} inserted, {} merged",
+                result.inserted, result.merged
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use opencode_mem_core::AppConfig;
 use opencode_mem_embeddings::LazyEmbeddingService;
-use opencode_mem_llm::LlmClient;
+use opencode_mem_llm::{LlmCircuitBreaker, LlmClient};
 use opencode_mem_mcp::run_mcp_server;
 use opencode_mem_service::{
     InfiniteMemoryService, KnowledgeService, ObservationService, SearchService, SessionService,
@@ -11,6 +11,9 @@ use tokio::sync::broadcast;
 
 pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
     opencode_mem_storage::init_queue_config(config.max_retry, config.visibility_timeout_secs);
+    opencode_mem_core::init_content_filter_config(config.strip_ansi);
+    opencode_mem_llm::init_prompt_guard_config(config.prompt_injection_guard);
+    opencode_mem_core::init_query_limit_config(config.max_result_limit);
     opencode_mem_service::init_compression_config(
         config.max_content_chars,
         config.max_total_chars,
@@ -18,11 +21,19 @@ pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
     );
     let storage = Arc::new(crate::create_storage(&config.database_url).await?);
 
-    let llm = Arc::new(LlmClient::new(
-        config.api_key.clone(),
-        config.api_url.clone(),
-        config.model.clone(),
-    )?);
+    let llm = Arc::new(
+        LlmClient::new(
+            config.api_key.clone(),
+            config.api_url.clone(),
+            config.model.clone(),
+        )?
+        .with_summary_language(config.summary_language.clone())
+        .with_circuit_breaker(LlmCircuitBreaker::new(
+            config.llm_breaker_threshold,
+            std::time::Duration::from_secs(config.llm_breaker_window_secs),
+            std::time::Duration::from_secs(config.llm_breaker_cooldown_secs),
+        )),
+    );
 
     let embeddings = if config.disable_embeddings {
         eprintln!("Embeddings disabled via OPENCODE_MEM_DISABLE_EMBEDDINGS");
@@ -32,6 +43,8 @@ pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
             config.embedding_threads,
         )))
     };
+    let reembed =
+        crate::check_embedding_dimension(&storage, &config, embeddings.as_deref()).await?;
 
     let infinite_mem = if let Some(ref url) = config.infinite_memory_url {
         let pool = sqlx::postgres::PgPoolOptions::new()
@@ -45,7 +58,10 @@ pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
             Ok(p) => match InfiniteMemoryService::new(p, llm.clone()).await {
                 Ok(mem) => {
                     eprintln!("Connected to infinite memory");
-                    Some(Arc::new(mem))
+                    Some(Arc::new(mem.with_event_batching(
+                        std::time::Duration::from_millis(config.infinite_batch_window_ms),
+                        config.infinite_batch_max_events,
+                    )))
                 }
                 Err(e) => {
                     eprintln!("Warning: Failed to initialize infinite memory: {e}");
@@ -72,7 +88,11 @@ pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
         embeddings.clone(),
         &config,
     ));
-    let session_service = Arc::new(SessionService::new(storage.clone(), llm.clone()));
+    let session_service = Arc::new(
+        SessionService::new(storage.clone(), llm.clone()).with_completion_grace(
+            std::time::Duration::from_secs(config.session_complete_grace_secs),
+        ),
+    );
     let knowledge_service = Arc::new(KnowledgeService::new(storage.clone(), embeddings.clone()));
     let search_service = Arc::new(SearchService::new(
         storage,
@@ -81,10 +101,27 @@ pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
         config.dedup_threshold,
     ));
 
+    if reembed {
+        let search_service = search_service.clone();
+        let knowledge_service = knowledge_service.clone();
+        tokio::spawn(async move {
+            match search_service.run_embedding_backfill(100).await {
+                Ok(generated) => eprintln!("Re-embedded {generated} observations"),
+                Err(e) => eprintln!("Warning: Re-embed backfill failed: {e}"),
+            }
+            match knowledge_service.run_embedding_backfill(100).await {
+                Ok(generated) => eprintln!("Re-embedded {generated} knowledge entries"),
+                Err(e) => eprintln!("Warning: Knowledge re-embed backfill failed: {e}"),
+            }
+        });
+    }
+
     let handle = tokio::runtime::Handle::current();
 
     let pending_writes = Arc::new(opencode_mem_service::PendingWriteQueue::new());
 
+    let infinite_mem_for_flush = infinite_mem.clone();
+
     run_mcp_server(
         infinite_mem,
         observation_service,
@@ -96,5 +133,9 @@ pub(crate) async fn run(config: Arc<AppConfig>) -> Result<()> {
     )
     .await;
 
+    if let Some(infinite_mem) = infinite_mem_for_flush {
+        infinite_mem.flush_events().await;
+    }
+
     Ok(())
 }
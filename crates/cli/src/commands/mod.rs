@@ -1,5 +1,6 @@
 pub(crate) mod hook;
 pub(crate) mod import_insights;
+pub(crate) mod knowledge;
 pub(crate) mod mcp;
 pub(crate) mod search;
 pub(crate) mod serve;
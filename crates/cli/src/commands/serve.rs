@@ -4,7 +4,7 @@ use opencode_mem_embeddings::LazyEmbeddingService;
 use opencode_mem_http::{
     AppState, Settings, create_router, run_startup_recovery, start_background_processor,
 };
-use opencode_mem_llm::LlmClient;
+use opencode_mem_llm::{LlmCircuitBreaker, LlmClient};
 use opencode_mem_service::{
     InfiniteMemoryService, KnowledgeService, ObservationService, QueueService, SearchService,
     SessionService,
@@ -16,17 +16,28 @@ use tokio::sync::{RwLock, Semaphore, broadcast};
 
 pub(crate) async fn run(port: u16, host: String, config: Arc<AppConfig>) -> Result<()> {
     opencode_mem_storage::init_queue_config(config.max_retry, config.visibility_timeout_secs);
+    opencode_mem_core::init_content_filter_config(config.strip_ansi);
+    opencode_mem_llm::init_prompt_guard_config(config.prompt_injection_guard);
+    opencode_mem_core::init_query_limit_config(config.max_result_limit);
     opencode_mem_service::init_compression_config(
         config.max_content_chars,
         config.max_total_chars,
         config.max_events,
     );
     let storage = Arc::new(crate::create_storage(&config.database_url).await?);
-    let llm = Arc::new(LlmClient::new(
-        config.api_key.clone(),
-        config.api_url.clone(),
-        config.model.clone(),
-    )?);
+    let llm = Arc::new(
+        LlmClient::new(
+            config.api_key.clone(),
+            config.api_url.clone(),
+            config.model.clone(),
+        )?
+        .with_summary_language(config.summary_language.clone())
+        .with_circuit_breaker(LlmCircuitBreaker::new(
+            config.llm_breaker_threshold,
+            std::time::Duration::from_secs(config.llm_breaker_window_secs),
+            std::time::Duration::from_secs(config.llm_breaker_cooldown_secs),
+        )),
+    );
     let (event_tx, _) = broadcast::channel(100);
 
     let infinite_mem = {
@@ -46,7 +57,10 @@ pub(crate) async fn run(port: u16, host: String, config: Arc<AppConfig>) -> Resu
             Ok(p) => match InfiniteMemoryService::new(p, llm.clone()).await {
                 Ok(mem) => {
                     tracing::info!("Connected to infinite memory");
-                    Some(Arc::new(mem))
+                    Some(Arc::new(mem.with_event_batching(
+                        std::time::Duration::from_millis(config.infinite_batch_window_ms),
+                        config.infinite_batch_max_events,
+                    )))
                 }
                 Err(e) => {
                     tracing::warn!("Failed to initialize infinite memory: {}", e);
@@ -68,6 +82,8 @@ pub(crate) async fn run(port: u16, host: String, config: Arc<AppConfig>) -> Resu
             config.embedding_threads,
         )))
     };
+    let reembed =
+        crate::check_embedding_dimension(&storage, &config, embeddings.as_deref()).await?;
 
     let pending_writes = Arc::new(opencode_mem_service::PendingWriteQueue::new());
 
@@ -79,7 +95,11 @@ pub(crate) async fn run(port: u16, host: String, config: Arc<AppConfig>) -> Resu
         embeddings.clone(),
         &config,
     ));
-    let session_service = Arc::new(SessionService::new(storage.clone(), llm.clone()));
+    let session_service = Arc::new(
+        SessionService::new(storage.clone(), llm.clone()).with_completion_grace(
+            std::time::Duration::from_secs(config.session_complete_grace_secs),
+        ),
+    );
     let knowledge_service = Arc::new(KnowledgeService::new(storage.clone(), embeddings.clone()));
     let search_service = Arc::new(SearchService::new(
         storage.clone(),
@@ -118,6 +138,24 @@ pub(crate) async fn run(port: u16, host: String, config: Arc<AppConfig>) -> Resu
 
     start_background_processor(state.clone());
 
+    if reembed {
+        let state_clone = state.clone();
+        state.background_tasks.lock().await.spawn(async move {
+            match state_clone.search_service.run_embedding_backfill(100).await {
+                Ok(generated) => tracing::info!(generated, "Re-embedded observations"),
+                Err(e) => tracing::warn!("Re-embed backfill failed: {}", e),
+            }
+            match state_clone
+                .knowledge_service
+                .run_embedding_backfill(100)
+                .await
+            {
+                Ok(generated) => tracing::info!(generated, "Re-embedded knowledge"),
+                Err(e) => tracing::warn!("Knowledge re-embed backfill failed: {}", e),
+            }
+        });
+    }
+
     let router = create_router(state.clone());
     let addr_str = format!("{host}:{port}");
     let addr: std::net::SocketAddr = addr_str.parse()?;
@@ -180,6 +218,10 @@ Or kill the process manually before starting a new server.",
     }
     tracing::info!("Background tasks finished.");
 
+    if let Some(ref infinite_mem) = state.infinite_mem {
+        infinite_mem.flush_events().await;
+    }
+
     if is_restart.load(std::sync::atomic::Ordering::Relaxed) {
         std::process::exit(1);
     }
@@ -56,8 +56,10 @@ mod commands;
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use commands::hook::HookCommands;
+use commands::knowledge::KnowledgeCommands;
 use opencode_mem_core::AppConfig;
-use opencode_mem_storage::StorageBackend;
+use opencode_mem_embeddings::{EmbeddingProvider, LazyEmbeddingService};
+use opencode_mem_storage::{StorageBackend, StorageError};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser)]
@@ -111,6 +113,8 @@ enum Commands {
     },
     #[command(subcommand)]
     Hook(HookCommands),
+    #[command(subcommand)]
+    Knowledge(KnowledgeCommands),
     KnowledgeLifecycle,
 }
 
@@ -125,6 +129,35 @@ pub async fn create_storage_from_env() -> Result<StorageBackend> {
     create_storage(&url).await
 }
 
+/// Fail fast when the stored vector columns were sized for a different
+/// embedding model. The model's dimension is the compile-time
+/// `EMBEDDING_DIMENSION` reported by the embedding service. With
+/// `reembed_on_dimension_change` the columns are recreated instead; returns
+/// `true` so the caller can re-embed observations and knowledge.
+/// An unreachable database is not fatal — the check is skipped like migrations.
+pub(crate) async fn check_embedding_dimension(
+    storage: &StorageBackend,
+    config: &AppConfig,
+    embeddings: Option<&LazyEmbeddingService>,
+) -> Result<bool> {
+    let Some(embeddings) = embeddings else {
+        return Ok(false);
+    };
+    match storage
+        .ensure_embedding_dimension(embeddings.dimension(), config.reembed_on_dimension_change)
+        .await
+    {
+        Ok(recreated) => Ok(recreated),
+        Err(e @ StorageError::EmbeddingDimensionMismatch { .. }) => Err(anyhow::anyhow!(
+            "{e}. Set OPENCODE_MEM_REEMBED_ON_DIMENSION_CHANGE=true to recreate the vector columns and re-embed all observations and knowledge"
+        )),
+        Err(e) => {
+            tracing::warn!("Skipping embedding dimension check: {e}");
+            Ok(false)
+        }
+    }
+}
+
 fn main() -> Result<()> {
     // Load config early (before tokio) to set OMP_NUM_THREADS.
     // Consolidate threading logic through core app_config (SPOT).
@@ -193,6 +226,9 @@ async fn async_main() -> Result<()> {
         Commands::Hook(hook_cmd) => {
             commands::hook::run(hook_cmd).await?;
         }
+        Commands::Knowledge(knowledge_cmd) => {
+            commands::knowledge::run(knowledge_cmd).await?;
+        }
         Commands::KnowledgeLifecycle => {
             commands::search::run_knowledge_lifecycle().await?;
         }
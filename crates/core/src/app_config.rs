@@ -4,7 +4,7 @@
 //! across 14 files. All environment variables are parsed here, validated, and exposed as
 //! properly typed fields.
 
-use crate::env_parse_with_default;
+use crate::{env_parse_with_default, parse_bool_env_with_default};
 
 /// Centralized application configuration.
 ///
@@ -13,7 +13,7 @@ use crate::env_parse_with_default;
 ///
 /// # Required variables
 /// - `DATABASE_URL` — PostgreSQL connection string
-/// - `OPENCODE_MEM_API_KEY` or `ANTIGRAVITY_API_KEY` — LLM API key
+/// - `OPENCODE_MEM_API_KEY_FILE`, `OPENCODE_MEM_API_KEY` or `ANTIGRAVITY_API_KEY` — LLM API key
 ///
 /// # Optional variables (with defaults)
 /// See field documentation for env var names and defaults.
@@ -25,7 +25,8 @@ pub struct AppConfig {
     pub database_url: String,
 
     /// LLM API key.
-    /// Env: `OPENCODE_MEM_API_KEY` or `ANTIGRAVITY_API_KEY`
+    /// Env: `OPENCODE_MEM_API_KEY_FILE` (path to a file holding the key, takes precedence),
+    /// `OPENCODE_MEM_API_KEY` or `ANTIGRAVITY_API_KEY`
     pub api_key: String,
 
     // === LLM ===
@@ -47,12 +48,32 @@ pub struct AppConfig {
     /// Env: `OPENCODE_MEM_EMBEDDING_THREADS` (default: `0` = auto)
     pub embedding_threads: usize,
 
+    /// When the stored vector columns were sized for a different model, drop
+    /// and recreate them at the model's dimension (`EMBEDDING_DIMENSION`) and
+    /// re-embed all observations and knowledge.
+    /// Without this, startup refuses with a "re-embedding required" error.
+    /// Env: `OPENCODE_MEM_REEMBED_ON_DIMENSION_CHANGE` (default: `false`)
+    pub reembed_on_dimension_change: bool,
+
     // === Infinite Memory ===
     /// Optional separate database URL for infinite memory.
     /// Falls back to `DATABASE_URL` if not set.
     /// Env: `INFINITE_MEMORY_URL` or `OPENCODE_MEM_INFINITE_MEMORY`
     pub infinite_memory_url: Option<String>,
 
+    /// Consecutive LLM failures (within the window) that open the LLM circuit
+    /// breaker. `0` disables the breaker.
+    /// Env: `OPENCODE_MEM_LLM_BREAKER_THRESHOLD` (default: `5`)
+    pub llm_breaker_threshold: u32,
+
+    /// Window in seconds in which the consecutive LLM failures must occur.
+    /// Env: `OPENCODE_MEM_LLM_BREAKER_WINDOW_SECS` (default: `60`)
+    pub llm_breaker_window_secs: u64,
+
+    /// Seconds LLM calls fail fast before a recovery probe is let through.
+    /// Env: `OPENCODE_MEM_LLM_BREAKER_COOLDOWN_SECS` (default: `30`)
+    pub llm_breaker_cooldown_secs: u64,
+
     // === Deduplication ===
     /// Cosine similarity threshold for observation deduplication.
     /// Clamped to `[0.0, 1.0]`.
@@ -81,6 +102,51 @@ pub struct AppConfig {
     /// Env: `OPENCODE_MEM_DLQ_TTL_DAYS` (default: `7`)
     pub dlq_ttl_days: i64,
 
+    // === Sessions ===
+    /// Maximum seconds `session_complete` waits for the session's pending and
+    /// in-flight queue messages to drain before summarizing. `0` disables the wait.
+    /// Env: `OPENCODE_MEM_SESSION_COMPLETE_GRACE_SECS` (default: `0`)
+    pub session_complete_grace_secs: u64,
+
+    // === Observation Quality ===
+    /// When the LLM returns no narrative, synthesize one from the observation's
+    /// facts before persisting. Off by default (null narratives are accepted).
+    /// Env: `OPENCODE_MEM_REQUIRE_NARRATIVE` (default: `false`)
+    pub require_narrative: bool,
+    /// Strip ANSI/VT escape sequences from tool output during input sanitization.
+    /// Env: `OPENCODE_MEM_STRIP_ANSI` (`1`/`0`/`true`/`false`, default: `true`)
+    pub strip_ansi: bool,
+    /// Fence tool output in the compression prompt as untrusted data so
+    /// embedded instructions are not followed.
+    /// Env: `OPENCODE_MEM_PROMPT_INJECTION_GUARD` (`1`/`0`/`true`/`false`, default: `true`)
+    pub prompt_injection_guard: bool,
+
+    /// Language session summaries and infinite-memory summaries are written in.
+    /// Env: `OPENCODE_MEM_SUMMARY_LANGUAGE` (default: `Russian`)
+    pub summary_language: String,
+
+    // === Context-Aware Compression ===
+    /// Maximum existing observations shown to the LLM as update/skip candidates.
+    /// `0` keeps every candidate found.
+    /// Env: `OPENCODE_MEM_MAX_CANDIDATES` (default: `0`)
+    pub max_candidates: usize,
+
+    /// Per-candidate budget for narrative + facts characters sent to the LLM.
+    /// Candidates over budget are reduced to title + subtitle. `0` disables the cap.
+    /// Env: `OPENCODE_MEM_MAX_CANDIDATE_CHARS` (default: `0`)
+    pub max_candidate_chars: usize,
+
+    /// Log a preview instead of applying risky context-aware merges
+    /// (e.g. into a manually saved observation).
+    /// Env: `OPENCODE_MEM_MERGE_REQUIRE_CONFIRM` (default: `false`)
+    pub merge_require_confirm: bool,
+
+    // === Retention ===
+    /// Lifetime in hours assigned to transient (low/negligible noise) observations.
+    /// Critical observations never expire. `0` disables automatic expiry.
+    /// Env: `OPENCODE_MEM_TRANSIENT_TTL_HOURS` (default: `0`)
+    pub transient_ttl_hours: u64,
+
     // === Infinite Memory Compression ===
     /// Maximum characters per event content field before truncation.
     /// Env: `OPENCODE_MEM_MAX_CONTENT_CHARS` (default: `500`)
@@ -94,6 +160,21 @@ pub struct AppConfig {
     /// Env: `OPENCODE_MEM_MAX_EVENTS` (default: `200`)
     pub max_events: usize,
 
+    /// How long raw events are buffered before being written as one batch.
+    /// `0` writes each event immediately.
+    /// Env: `OPENCODE_MEM_INFINITE_BATCH_WINDOW_MS` (default: `0`)
+    pub infinite_batch_window_ms: u64,
+
+    /// Buffered event count that triggers an early flush (capped at 1000).
+    /// Env: `OPENCODE_MEM_INFINITE_BATCH_MAX_EVENTS` (default: `100`)
+    pub infinite_batch_max_events: usize,
+
+    // === API ===
+    /// Ceiling for caller-supplied `limit` on every list/search endpoint and MCP tool.
+    /// Larger requests are clamped. Capped at `MAX_QUERY_LIMIT`.
+    /// Env: `OPENCODE_MEM_MAX_RESULT_LIMIT` (default: `100`)
+    pub max_result_limit: usize,
+
     /// Administrative token for sensitive operations.
     /// Env: `OPENCODE_MEM_ADMIN_TOKEN`
     pub admin_token: Option<String>,
@@ -103,6 +184,11 @@ pub struct AppConfig {
 
     /// Raw patterns for low-value observation filtering.
     pub filter_patterns_raw: Option<String>,
+
+    /// Models a caller may select per request via the `X-Model` header.
+    /// Empty means per-request overrides are rejected.
+    /// Env: `OPENCODE_MEM_ALLOWED_MODELS` (comma-separated, default: empty)
+    pub allowed_models: Vec<String>,
 }
 
 /// Error returned when required configuration is missing or invalid.
@@ -111,13 +197,35 @@ pub enum ConfigError {
     /// A required environment variable is missing.
     #[error("{0}")]
     Missing(String),
+
+    /// A secret file named by an environment variable could not be used.
+    #[error("{var}: cannot read secret file {path}: {reason}")]
+    SecretFile {
+        var: &'static str,
+        path: String,
+        reason: String,
+    },
+}
+
+/// Read a mounted secret (e.g. a Kubernetes/Docker secret) named by `var`.
+/// Surrounding whitespace is trimmed; an empty file is an error.
+fn read_secret_file(var: &'static str, path: &str) -> Result<String, ConfigError> {
+    let error = |reason: String| ConfigError::SecretFile {
+        var,
+        path: path.to_owned(),
+        reason,
+    };
+    let contents = std::fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+    let secret = contents.trim();
+    if secret.is_empty() {
+        return Err(error("file is empty".to_owned()));
+    }
+    Ok(secret.to_owned())
 }
 
-/// Parse a boolean from env var (accepts `1`, `true` case-insensitive).
+/// Parse an opt-in boolean flag (unset means `false`).
 fn parse_bool_env(var: &str) -> bool {
-    std::env::var(var)
-        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
-        .unwrap_or(false)
+    parse_bool_env_with_default(var, false)
 }
 
 /// Parse an f32 env var with default, then clamp to `[0.0, 1.0]` with a warning.
@@ -137,11 +245,22 @@ fn parse_clamped_threshold(var: &str, default: f32) -> f32 {
 
 impl AppConfig {
     /// Resolve LLM API key with fallbacks.
-    pub fn resolve_api_key() -> Option<String> {
-        std::env::var("OPENCODE_MEM_API_KEY")
+    ///
+    /// `OPENCODE_MEM_API_KEY_FILE` wins over the inline variables so the key
+    /// can be mounted as a secret instead of exposed in the process environment.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::SecretFile` if the key file is unreadable or empty.
+    pub fn resolve_api_key() -> Result<Option<String>, ConfigError> {
+        if let Ok(path) = std::env::var("OPENCODE_MEM_API_KEY_FILE")
+            && !path.trim().is_empty()
+        {
+            return read_secret_file("OPENCODE_MEM_API_KEY_FILE", path.trim()).map(Some);
+        }
+        Ok(std::env::var("OPENCODE_MEM_API_KEY")
             .or_else(|_| std::env::var("ANTIGRAVITY_API_KEY"))
             .or_else(|_| std::env::var("OPENAI_API_KEY"))
-            .ok()
+            .ok())
     }
 
     /// Resolve LLM API URL with fallbacks.
@@ -172,15 +291,16 @@ impl AppConfig {
     ///
     /// # Errors
     /// Returns `ConfigError::Missing` if required env vars (`DATABASE_URL`,
-    /// `OPENCODE_MEM_API_KEY`/`ANTIGRAVITY_API_KEY`) are not set.
+    /// `OPENCODE_MEM_API_KEY`/`ANTIGRAVITY_API_KEY`) are not set, or
+    /// `ConfigError::SecretFile` if `OPENCODE_MEM_API_KEY_FILE` cannot be read.
     pub fn from_env() -> Result<Self, ConfigError> {
         let database_url = std::env::var("DATABASE_URL").map_err(|_| {
             ConfigError::Missing("DATABASE_URL environment variable must be set".to_owned())
         })?;
 
-        let api_key = Self::resolve_api_key().ok_or_else(|| {
+        let api_key = Self::resolve_api_key()?.ok_or_else(|| {
             ConfigError::Missing(
-                "OPENCODE_MEM_API_KEY or ANTIGRAVITY_API_KEY or OPENAI_API_KEY environment variable must be set"
+                "OPENCODE_MEM_API_KEY_FILE or OPENCODE_MEM_API_KEY or ANTIGRAVITY_API_KEY or OPENAI_API_KEY environment variable must be set"
                     .to_owned(),
             )
         })?;
@@ -192,12 +312,21 @@ impl AppConfig {
         let disable_embeddings = parse_bool_env("OPENCODE_MEM_DISABLE_EMBEDDINGS");
 
         let embedding_threads = Self::resolve_embedding_threads();
+        let reembed_on_dimension_change =
+            parse_bool_env("OPENCODE_MEM_REEMBED_ON_DIMENSION_CHANGE");
 
         let infinite_memory_url = std::env::var("INFINITE_MEMORY_URL")
             .or_else(|_| std::env::var("OPENCODE_MEM_INFINITE_MEMORY"))
             .ok()
             .or_else(|| Some(database_url.clone()));
 
+        let llm_breaker_threshold =
+            env_parse_with_default("OPENCODE_MEM_LLM_BREAKER_THRESHOLD", 5_u32);
+        let llm_breaker_window_secs =
+            env_parse_with_default("OPENCODE_MEM_LLM_BREAKER_WINDOW_SECS", 60_u64);
+        let llm_breaker_cooldown_secs =
+            env_parse_with_default("OPENCODE_MEM_LLM_BREAKER_COOLDOWN_SECS", 30_u64);
+
         let dedup_threshold = parse_clamped_threshold("OPENCODE_MEM_DEDUP_THRESHOLD", 0.85);
         let injection_dedup_threshold =
             parse_clamped_threshold("OPENCODE_MEM_INJECTION_DEDUP_THRESHOLD", 0.80);
@@ -207,14 +336,47 @@ impl AppConfig {
         let visibility_timeout_secs =
             env_parse_with_default("OPENCODE_MEM_VISIBILITY_TIMEOUT", 300_i64);
         let dlq_ttl_days = env_parse_with_default("OPENCODE_MEM_DLQ_TTL_DAYS", 7_i64);
+        let session_complete_grace_secs =
+            env_parse_with_default("OPENCODE_MEM_SESSION_COMPLETE_GRACE_SECS", 0_u64);
+        let require_narrative = parse_bool_env("OPENCODE_MEM_REQUIRE_NARRATIVE");
+        let strip_ansi = parse_bool_env_with_default("OPENCODE_MEM_STRIP_ANSI", true);
+        let prompt_injection_guard =
+            parse_bool_env_with_default("OPENCODE_MEM_PROMPT_INJECTION_GUARD", true);
+        let summary_language = std::env::var("OPENCODE_MEM_SUMMARY_LANGUAGE")
+            .ok()
+            .map(|s| s.trim().to_owned())
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| crate::DEFAULT_SUMMARY_LANGUAGE.to_owned());
+        let max_candidates = env_parse_with_default("OPENCODE_MEM_MAX_CANDIDATES", 0_usize);
+        let max_candidate_chars =
+            env_parse_with_default("OPENCODE_MEM_MAX_CANDIDATE_CHARS", 0_usize);
+        let merge_require_confirm = parse_bool_env("OPENCODE_MEM_MERGE_REQUIRE_CONFIRM");
+        let transient_ttl_hours = env_parse_with_default("OPENCODE_MEM_TRANSIENT_TTL_HOURS", 0_u64);
 
         let max_content_chars = env_parse_with_default("OPENCODE_MEM_MAX_CONTENT_CHARS", 500_usize);
         let max_total_chars = env_parse_with_default("OPENCODE_MEM_MAX_TOTAL_CHARS", 8000_usize);
         let max_events = env_parse_with_default("OPENCODE_MEM_MAX_EVENTS", 200_usize);
+        let infinite_batch_window_ms =
+            env_parse_with_default("OPENCODE_MEM_INFINITE_BATCH_WINDOW_MS", 0_u64);
+        let infinite_batch_max_events =
+            env_parse_with_default("OPENCODE_MEM_INFINITE_BATCH_MAX_EVENTS", 100_usize);
+        let max_result_limit = env_parse_with_default(
+            "OPENCODE_MEM_MAX_RESULT_LIMIT",
+            crate::DEFAULT_MAX_RESULT_LIMIT,
+        );
 
         let admin_token = std::env::var("OPENCODE_MEM_ADMIN_TOKEN").ok();
         let excluded_projects_raw = std::env::var("OPENCODE_MEM_EXCLUDED_PROJECTS").ok();
         let filter_patterns_raw = std::env::var("OPENCODE_MEM_FILTER_PATTERNS").ok();
+        let allowed_models = std::env::var("OPENCODE_MEM_ALLOWED_MODELS")
+            .map(|raw| {
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|m| !m.is_empty())
+                    .map(str::to_owned)
+                    .collect()
+            })
+            .unwrap_or_default();
 
         Ok(Self {
             database_url,
@@ -223,19 +385,36 @@ impl AppConfig {
             model,
             disable_embeddings,
             embedding_threads,
+            reembed_on_dimension_change,
             infinite_memory_url,
+            llm_breaker_threshold,
+            llm_breaker_window_secs,
+            llm_breaker_cooldown_secs,
             dedup_threshold,
             injection_dedup_threshold,
             queue_workers,
             max_retry,
             visibility_timeout_secs,
             dlq_ttl_days,
+            session_complete_grace_secs,
+            require_narrative,
+            strip_ansi,
+            prompt_injection_guard,
+            summary_language,
+            max_candidates,
+            max_candidate_chars,
+            merge_require_confirm,
+            transient_ttl_hours,
             max_content_chars,
             max_total_chars,
             max_events,
+            infinite_batch_window_ms,
+            infinite_batch_max_events,
+            max_result_limit,
             admin_token,
             excluded_projects_raw,
             filter_patterns_raw,
+            allowed_models,
         })
     }
 
@@ -244,4 +423,46 @@ impl AppConfig {
     pub fn dlq_ttl_secs(&self) -> i64 {
         self.dlq_ttl_days.saturating_mul(86400)
     }
+
+    /// Whether `model` may be selected per request via the `X-Model` header.
+    #[must_use]
+    pub fn is_model_allowed(&self, model: &str) -> bool {
+        self.allowed_models.iter().any(|m| m == model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_secret(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("opencode-mem-{name}-{}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_secret_file_is_trimmed() {
+        let path = temp_secret("key", "  sk-test-123\n");
+        let key = read_secret_file("OPENCODE_MEM_API_KEY_FILE", path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+        assert_eq!(key.unwrap(), "sk-test-123");
+    }
+
+    #[test]
+    fn test_empty_secret_file_is_rejected() {
+        let path = temp_secret("empty", "\n");
+        let err = read_secret_file("OPENCODE_MEM_API_KEY_FILE", path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+        assert!(err.unwrap_err().to_string().contains("file is empty"));
+    }
+
+    #[test]
+    fn test_missing_secret_file_names_variable_and_path() {
+        let err = read_secret_file("OPENCODE_MEM_API_KEY_FILE", "/nonexistent/opencode-mem-key")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("OPENCODE_MEM_API_KEY_FILE"));
+        assert!(err.contains("/nonexistent/opencode-mem-key"));
+    }
 }
@@ -2,6 +2,8 @@
 //!
 //! Centralizes magic numbers that were previously duplicated across crates.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 /// Maximum number of results for any query (DoS protection).
 pub const MAX_QUERY_LIMIT: usize = 1000;
 
@@ -29,6 +31,10 @@ pub const DEFAULT_QUERY_LIMIT: usize = 20;
 pub const INFINITE_MEMORY_NOT_CONFIGURED: &str =
     "Infinite Memory not configured (INFINITE_MEMORY_URL not set)";
 
+/// Language of session and infinite-memory summaries unless overridden by
+/// `OPENCODE_MEM_SUMMARY_LANGUAGE`.
+pub const DEFAULT_SUMMARY_LANGUAGE: &str = "Russian";
+
 /// Embedding vector dimension (BGE-M3 model: 1024d, 100+ languages).
 pub const EMBEDDING_DIMENSION: usize = 1024;
 
@@ -56,11 +62,46 @@ pub const KNOWLEDGE_TRIGRAM_CANDIDATE_LIMIT: i64 = 3;
 /// and semantically denser — false positives are costlier.
 pub const KNOWLEDGE_SEMANTIC_DEDUP_THRESHOLD: f32 = 0.85;
 
-/// Cap a user-supplied query limit to `MAX_QUERY_LIMIT`.
+/// Default ceiling for user-supplied result limits.
+pub const DEFAULT_MAX_RESULT_LIMIT: usize = 100;
+
+/// Active ceiling used by [`cap_result_limit`]. Set via `init_query_limit_config` at startup.
+static MAX_RESULT_LIMIT: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_RESULT_LIMIT);
+
+/// Initialize the result limit ceiling from `AppConfig` at startup.
+/// Clamped to `1..=MAX_QUERY_LIMIT` so a misconfiguration cannot lift the hard cap.
+pub fn init_query_limit_config(max_result_limit: usize) {
+    MAX_RESULT_LIMIT.store(
+        max_result_limit.clamp(1, MAX_QUERY_LIMIT),
+        Ordering::Relaxed,
+    );
+}
+
+/// Current ceiling for user-supplied result limits.
+#[must_use]
+pub fn max_result_limit() -> usize {
+    MAX_RESULT_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Cap a user-supplied result limit to the configured [`max_result_limit`].
 ///
 /// Both HTTP and MCP transports need to clamp user-supplied limits for DoS
 /// protection. This is the single point of truth (SPOT) — transport-specific
-/// `capped_limit()` methods and `parse_limit()` delegate here.
+/// `capped_limit()` methods and `parse_limit()` delegate here. Services use
+/// [`cap_query_limit`] instead, so internal callers that legitimately need
+/// more rows (e.g. context injection's tiered knowledge selection) are not
+/// cut down to the user-facing ceiling.
+#[must_use]
+pub fn cap_result_limit(limit: usize) -> usize {
+    let max = max_result_limit();
+    if limit <= max {
+        return limit;
+    }
+    tracing::info!(requested = limit, max, "Clamping result limit");
+    max
+}
+
+/// Cap a query limit to the hard `MAX_QUERY_LIMIT`.
 #[must_use]
 pub const fn cap_query_limit(limit: usize) -> usize {
     if limit < MAX_QUERY_LIMIT {
@@ -69,3 +110,19 @@ pub const fn cap_query_limit(limit: usize) -> usize {
         MAX_QUERY_LIMIT
     }
 }
+
+/// Normalize a user-supplied free-text search query.
+///
+/// Returns the trimmed query, or `None` when it is empty or whitespace-only.
+/// This is the single policy point for blank queries: query-required searches
+/// (hybrid, semantic, FTS) return an empty list for `None`, while searches with
+/// an optional query treat `None` as "no text filter" and list by recency.
+#[must_use]
+pub fn normalize_query(query: &str) -> Option<&str> {
+    let trimmed = query.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed)
+    }
+}
@@ -28,6 +28,34 @@ pub fn env_parse_with_default<T: std::str::FromStr + std::fmt::Display>(
     }
 }
 
+/// Parse a boolean environment variable with a default fallback.
+///
+/// Accepts `1`/`0` and `true`/`false` (case-insensitive, surrounding whitespace
+/// ignored). Unset or blank returns `default`; anything else logs a warning and
+/// returns `default`. Use this for every boolean flag so `0`/`1` and
+/// `true`/`false` mean the same thing everywhere.
+pub fn parse_bool_env_with_default(var: &str, default: bool) -> bool {
+    let Ok(raw) = std::env::var(var) else {
+        return default;
+    };
+    let value = raw.trim();
+    if value.is_empty() {
+        default
+    } else if value == "1" || value.eq_ignore_ascii_case("true") {
+        true
+    } else if value == "0" || value.eq_ignore_ascii_case("false") {
+        false
+    } else {
+        tracing::warn!(
+            var,
+            value = %raw,
+            default,
+            "invalid boolean env var value, using default"
+        );
+        default
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +98,40 @@ mod tests {
         assert_eq!(result, 10);
         unsafe { std::env::remove_var(var_name) };
     }
+
+    #[test]
+    fn test_parse_bool_env_accepts_numeric_and_word_forms() {
+        let var_name = "TEST_ENV_PARSE_BOOL_98274";
+        for (value, expected) in [
+            ("1", true),
+            ("true", true),
+            (" TRUE ", true),
+            ("0", false),
+            ("false", false),
+            ("False", false),
+        ] {
+            // SAFETY: Test-only, single-threaded test runner
+            unsafe { std::env::set_var(var_name, value) };
+            assert_eq!(
+                parse_bool_env_with_default(var_name, !expected),
+                expected,
+                "{value:?}"
+            );
+        }
+        unsafe { std::env::remove_var(var_name) };
+    }
+
+    #[test]
+    fn test_parse_bool_env_falls_back_to_default() {
+        let var_name = "TEST_ENV_PARSE_BOOL_98275";
+        // SAFETY: Test-only, single-threaded test runner
+        unsafe { std::env::remove_var(var_name) };
+        assert!(parse_bool_env_with_default(var_name, true));
+        for value in ["", "yes", "2"] {
+            unsafe { std::env::set_var(var_name, value) };
+            assert!(parse_bool_env_with_default(var_name, true), "{value:?}");
+            assert!(!parse_bool_env_with_default(var_name, false), "{value:?}");
+        }
+        unsafe { std::env::remove_var(var_name) };
+    }
 }
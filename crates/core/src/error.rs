@@ -17,4 +17,7 @@ pub enum CoreError {
     /// Failed to parse a noise level string.
     #[error("invalid noise level: {0}")]
     InvalidNoiseLevel(String),
+    /// Failed to parse a sort order string.
+    #[error("invalid sort: {0} (expected one of: {expected})", expected = crate::ObservationSort::ALL_VARIANTS_STR)]
+    InvalidSort(String),
 }
@@ -22,6 +22,8 @@ pub enum HookEvent {
     Observation,
     /// Summarize - generate session summary
     Summarize,
+    /// Commit - record a git commit (from a `post-commit` hook)
+    Commit,
 }
 
 impl Display for HookEvent {
@@ -31,6 +33,7 @@ impl Display for HookEvent {
             Self::SessionInit => write!(f, "session-init"),
             Self::Observation => write!(f, "observation"),
             Self::Summarize => write!(f, "summarize"),
+            Self::Commit => write!(f, "commit"),
         }
     }
 }
@@ -44,6 +47,7 @@ impl FromStr for HookEvent {
             "session-init" | "session_init" => Ok(Self::SessionInit),
             "observation" | "observe" => Ok(Self::Observation),
             "summarize" => Ok(Self::Summarize),
+            "commit" => Ok(Self::Commit),
             _ => Err(CoreError::InvalidHookEvent(s.to_owned())),
         }
     }
@@ -159,3 +163,41 @@ impl SummarizeHookRequest {
         }
     }
 }
+
+/// Request payload for commit hook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CommitHookRequest {
+    /// Commit SHA.
+    pub sha: String,
+    /// Full commit message; the first line becomes the observation title.
+    pub message: String,
+    /// Paths changed by the commit.
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Project path or name.
+    pub project: Option<String>,
+    /// Session ID to attribute the commit to, if the commit happened inside one.
+    #[serde(rename = "sessionId")]
+    pub session_id: Option<String>,
+}
+
+impl CommitHookRequest {
+    /// Creates a new commit hook request.
+    #[must_use]
+    pub const fn new(
+        sha: String,
+        message: String,
+        files: Vec<String>,
+        project: Option<String>,
+        session_id: Option<String>,
+    ) -> Self {
+        Self {
+            sha,
+            message,
+            files,
+            project,
+            session_id,
+        }
+    }
+}
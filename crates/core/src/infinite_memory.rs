@@ -187,6 +187,31 @@ pub fn tool_event(
     }
 }
 
+/// Helper to create a git commit event for infinite memory.
+///
+/// Keyed by SHA through `call_id`, so re-sending a commit stores it once.
+#[must_use]
+pub fn commit_event(
+    session_id: &str,
+    project: Option<&str>,
+    sha: &str,
+    message: &str,
+    files: Vec<String>,
+) -> RawInfiniteEvent {
+    RawInfiniteEvent {
+        session_id: session_id.to_string(),
+        project: project.map(|s| s.to_string()),
+        event_type: InfiniteEventType::Commit,
+        content: serde_json::json!({
+            "sha": sha,
+            "message": message
+        }),
+        files,
+        tools: vec![],
+        call_id: Some(format!("commit-{sha}")),
+    }
+}
+
 /// Helper to create a user message event for infinite memory.
 #[must_use]
 pub fn user_event(session_id: &str, project: Option<&str>, message: &str) -> RawInfiniteEvent {
@@ -36,6 +36,77 @@ pub fn strip_markdown_json(content: &str) -> &str {
         .map_or_else(|| after_open.trim(), |(_, rest)| rest.trim())
 }
 
+/// Extract the JSON payload from an LLM response.
+///
+/// Tries [`strip_markdown_json`] first. If that does not yield valid JSON — e.g. the
+/// object is wrapped in prose or followed by commentary — returns the first balanced
+/// `{...}` object that parses, falling back to the stripped content otherwise so the
+/// caller's parse error still reflects the original response.
+#[must_use]
+pub fn extract_json(content: &str) -> &str {
+    let stripped = strip_markdown_json(content);
+    if is_valid_json(stripped) {
+        return stripped;
+    }
+    find_json_object(stripped)
+        .or_else(|| find_json_object(content))
+        .unwrap_or(stripped)
+}
+
+fn is_valid_json(candidate: &str) -> bool {
+    serde_json::from_str::<serde::de::IgnoredAny>(candidate).is_ok()
+}
+
+/// First balanced `{...}` in `content` that parses as JSON.
+///
+/// Brace counting ignores braces inside string literals (including escaped quotes).
+/// Candidates that are balanced but invalid are skipped, so a stray `{` in prose
+/// does not hide a later object.
+fn find_json_object(content: &str) -> Option<&str> {
+    let mut search_from = 0;
+    while let Some(offset) = content.get(search_from..)?.find('{') {
+        let start = search_from.saturating_add(offset);
+        if let Some(end) = balanced_object_end(&content[start..]) {
+            let candidate = &content[start..start.saturating_add(end)];
+            if is_valid_json(candidate) {
+                return Some(candidate);
+            }
+        }
+        search_from = start.saturating_add(1);
+    }
+    None
+}
+
+/// Byte length of the object starting at `content[0] == '{'`, or `None` if unbalanced.
+fn balanced_object_end(content: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in content.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth = depth.saturating_add(1),
+            '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i.saturating_add(1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +173,33 @@ mod tests {
         let input2 = "`````";
         assert_eq!(strip_markdown_json(input2), "`````");
     }
+
+    #[test]
+    fn test_extract_json_prose_wrapped() {
+        let input =
+            "Here is the observation: {\"title\": \"Fix {braces}\", \"n\": 1} as requested.";
+        assert_eq!(
+            extract_json(input),
+            "{\"title\": \"Fix {braces}\", \"n\": 1}"
+        );
+    }
+
+    #[test]
+    fn test_extract_json_trailing_sentence() {
+        let input = "{\"key\": \"va\\\"l}ue\"}\nLet me know if you need anything else.";
+        assert_eq!(extract_json(input), "{\"key\": \"va\\\"l}ue\"}");
+    }
+
+    #[test]
+    fn test_extract_json_skips_stray_brace_in_prose() {
+        let input = "Use {placeholders} sparingly. {\"key\": {\"nested\": true}}";
+        assert_eq!(extract_json(input), "{\"key\": {\"nested\": true}}");
+    }
+
+    #[test]
+    fn test_extract_json_keeps_valid_fenced_and_array_payloads() {
+        assert_eq!(extract_json("```json\n[{\"a\": 1}]\n```"), "[{\"a\": 1}]");
+        assert_eq!(extract_json("no json here"), "no json here");
+        assert_eq!(extract_json("{\"unclosed\": "), "{\"unclosed\":");
+    }
 }
@@ -196,6 +196,69 @@ impl KnowledgeSearchResult {
     }
 }
 
+/// Portable knowledge entry for bulk import/export.
+///
+/// Carries only the curated content of an entry — IDs, confidence and usage
+/// statistics are deployment-local and regenerated on import.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct KnowledgeExportEntry {
+    /// Type of knowledge
+    pub knowledge_type: KnowledgeType,
+    /// Concise title (dedup key on import)
+    pub title: String,
+    /// Detailed description
+    pub description: String,
+    /// For skills: step-by-step how to apply
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Keywords/contexts when to use this knowledge
+    #[serde(default)]
+    pub triggers: Vec<String>,
+    /// Projects where this was learned
+    #[serde(default)]
+    pub source_projects: Vec<String>,
+}
+
+impl From<GlobalKnowledge> for KnowledgeExportEntry {
+    fn from(k: GlobalKnowledge) -> Self {
+        Self {
+            knowledge_type: k.knowledge_type,
+            title: k.title,
+            description: k.description,
+            instructions: k.instructions,
+            triggers: k.triggers,
+            source_projects: k.source_projects,
+        }
+    }
+}
+
+impl KnowledgeExportEntry {
+    /// Converts this entry into a `KnowledgeInput` attributed to `source_project`.
+    #[must_use]
+    pub fn to_input(&self, source_project: Option<String>) -> KnowledgeInput {
+        KnowledgeInput::new(
+            self.knowledge_type,
+            self.title.clone(),
+            self.description.clone(),
+            self.instructions.clone(),
+            self.triggers.clone(),
+            source_project,
+            None,
+        )
+    }
+}
+
+/// Outcome of a bulk knowledge import.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct KnowledgeImportResult {
+    /// Entries that created a new knowledge row
+    pub inserted: usize,
+    /// Entries merged into an existing row by title dedup
+    pub merged: usize,
+}
+
 /// LLM extraction result for knowledge promotion
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -23,7 +23,7 @@ pub use hook::*;
 pub use identifiers::*;
 pub use infinite_memory::{
     InfiniteEventType, InfiniteSummary, RawInfiniteEvent, StoredInfiniteEvent, SummaryEntities,
-    assistant_event, tool_event, user_event,
+    assistant_event, commit_event, tool_event, user_event,
 };
 pub use json_utils::*;
 pub use knowledge::*;
@@ -129,4 +129,34 @@ mod tests {
             "a and"
         );
     }
+
+    #[test]
+    fn normalize_query_blank_inputs() {
+        for blank in ["", "   ", "   \n", "\t\r\n"] {
+            assert_eq!(normalize_query(blank), None, "{blank:?} should be blank");
+        }
+    }
+
+    #[test]
+    fn normalize_query_trims() {
+        assert_eq!(normalize_query("  rust async \n"), Some("rust async"));
+    }
+
+    #[test]
+    fn observation_sort_round_trips() {
+        for sort in [
+            ObservationSort::CreatedDesc,
+            ObservationSort::CreatedAsc,
+            ObservationSort::Type,
+            ObservationSort::Noise,
+            ObservationSort::Score,
+        ] {
+            assert_eq!(sort.as_str().parse::<ObservationSort>(), Ok(sort));
+        }
+        assert_eq!(
+            "CREATED_ASC".parse::<ObservationSort>(),
+            Ok(ObservationSort::CreatedAsc)
+        );
+        assert!("newest".parse::<ObservationSort>().is_err());
+    }
 }
@@ -41,6 +41,10 @@ pub struct Observation {
     pub noise_reason: Option<String>,
     /// When this observation was created
     pub created_at: DateTime<Utc>,
+    /// When this observation expires and becomes eligible for the expiry sweep
+    /// (`None` = never expires)
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 impl Observation {
@@ -112,6 +116,7 @@ pub struct ObservationBuilder {
     noise_level: NoiseLevel,
     noise_reason: Option<String>,
     created_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
 }
 
 impl ObservationBuilder {
@@ -140,6 +145,7 @@ impl ObservationBuilder {
             noise_level: NoiseLevel::default(),
             noise_reason: None,
             created_at: Utc::now(),
+            expires_at: None,
         }
     }
 
@@ -257,6 +263,18 @@ impl ObservationBuilder {
         self
     }
 
+    #[must_use]
+    pub fn expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    #[must_use]
+    pub fn maybe_expires_at(mut self, expires_at: Option<DateTime<Utc>>) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+
     #[must_use]
     pub fn build(self) -> Observation {
         Observation {
@@ -277,6 +295,7 @@ impl ObservationBuilder {
             noise_level: self.noise_level,
             noise_reason: self.noise_reason,
             created_at: self.created_at,
+            expires_at: self.expires_at,
         }
     }
 }
@@ -1,7 +1,17 @@
-//! Content filtering for private tags and injected memory blocks.
+//! Content filtering for private tags, injected memory blocks and terminal escapes.
 
 use regex::Regex;
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether `sanitize_input` strips ANSI escape sequences. On unless disabled
+/// via `init_content_filter_config` at startup.
+static STRIP_ANSI: AtomicBool = AtomicBool::new(true);
+
+/// Initialize content filter config from `AppConfig` at startup.
+pub fn init_content_filter_config(strip_ansi: bool) {
+    STRIP_ANSI.store(strip_ansi, Ordering::Relaxed);
+}
 
 /// Safely removes XML-like blocks while properly handling nesting.
 /// Avoids O(N) allocations and regex limitations around nested structures.
@@ -177,13 +187,38 @@ pub fn filter_injected_memory(text: &str) -> String {
         .into_owned()
 }
 
+/// Regex for ANSI/VT escape sequences: CSI (colors, cursor movement),
+/// OSC (titles, hyperlinks; BEL- or ST-terminated) and two-byte escapes.
+#[expect(
+    clippy::unwrap_used,
+    reason = "static regex pattern is compile-time validated"
+)]
+static ANSI_ESCAPE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)|\x1b[@-Z\\-_]").unwrap()
+});
+
+/// Removes ANSI/VT escape sequences emitted by terminal tools (e.g. colored
+/// `cargo test` output), leaving the visible text intact.
+pub fn strip_ansi(text: &str) -> String {
+    if !text.contains('\x1b') {
+        return text.to_owned();
+    }
+    ANSI_ESCAPE_REGEX.replace_all(text, "").into_owned()
+}
+
 /// Standardized sanitization pipeline. Applies all required filters in the correct order.
 ///
 /// Current pipeline:
-/// 1. Remove injected memory tags (to prevent infinite context loops)
-/// 2. Remove private content (to prevent leaking sensitive data)
+/// 1. Strip ANSI escape sequences (unless disabled via `OPENCODE_MEM_STRIP_ANSI`)
+/// 2. Remove injected memory tags (to prevent infinite context loops)
+/// 3. Remove private content (to prevent leaking sensitive data)
 pub fn sanitize_input(text: &str) -> String {
-    let no_injected = filter_injected_memory(text);
+    let no_ansi = if STRIP_ANSI.load(Ordering::Relaxed) {
+        strip_ansi(text)
+    } else {
+        text.to_owned()
+    };
+    let no_injected = filter_injected_memory(&no_ansi);
     filter_private_content(&no_injected)
 }
 
@@ -0,0 +1,38 @@
+use super::super::*;
+
+#[test]
+fn strip_ansi_cleans_colored_cargo_test_output() {
+    let input = "\x1b[0m\x1b[1m\x1b[32m   Compiling\x1b[0m opencode-mem-core v0.1.0\n\
+                 running 2 tests\n\
+                 test parses_config ... \x1b[32mok\x1b[0m\n\
+                 test rejects_blank ... \x1b[31mFAILED\x1b[0m\n\
+                 \n\
+                 test result: \x1b[31mFAILED\x1b[0m. 1 passed; 1 failed\n\
+                 \x1b[1m\x1b[31merror\x1b[0m\x1b[1m:\x1b[0m test failed, to rerun pass `--lib`";
+    let expected = "   Compiling opencode-mem-core v0.1.0\n\
+                    running 2 tests\n\
+                    test parses_config ... ok\n\
+                    test rejects_blank ... FAILED\n\
+                    \n\
+                    test result: FAILED. 1 passed; 1 failed\n\
+                    error: test failed, to rerun pass `--lib`";
+    assert_eq!(strip_ansi(input), expected);
+}
+
+#[test]
+fn strip_ansi_removes_cursor_and_osc_sequences() {
+    let input = "\x1b[2K\x1b[1Gprogress\x1b]0;title\x07 \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\";
+    assert_eq!(strip_ansi(input), "progress link");
+}
+
+#[test]
+fn strip_ansi_leaves_plain_text_untouched() {
+    let input = "plain [text] with brackets; no escapes";
+    assert_eq!(strip_ansi(input), input);
+}
+
+#[test]
+fn sanitize_input_strips_ansi_by_default() {
+    let input = "\x1b[32mok\x1b[0m <private>secret</private>";
+    assert_eq!(sanitize_input(input), "ok ");
+}
@@ -1 +1,2 @@
+mod ansi_filter_tests;
 mod private_filter_tests;
@@ -4,6 +4,8 @@
 //! `merge_into_existing` implementation so the computation lives in one place
 //! (SPOT) and the storage backend only handles DB transactions.
 
+use serde::Serialize;
+
 use super::dedup::{union_dedup, union_dedup_concepts};
 use super::{Concept, DiscoveryTokens, NoiseLevel, Observation, ObservationType, PromptNumber};
 
@@ -113,6 +115,114 @@ pub fn compute_merge(
     }
 }
 
+/// One field a merge would change, with JSON-serialized before/after values.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldDiff {
+    pub field: &'static str,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+/// Dry-run of merging an observation into an existing one.
+///
+/// Lists only the fields whose value would change; timestamps are omitted
+/// because every merge bumps them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MergePreview {
+    pub target_id: String,
+    pub diffs: Vec<FieldDiff>,
+}
+
+impl MergePreview {
+    /// Whether the merge would leave the target unchanged.
+    #[must_use]
+    pub fn is_noop(&self) -> bool {
+        self.diffs.is_empty()
+    }
+
+    /// Diff for `field`, if the merge would change it.
+    #[must_use]
+    pub fn diff(&self, field: &str) -> Option<&FieldDiff> {
+        self.diffs.iter().find(|d| d.field == field)
+    }
+}
+
+/// Compute what [`compute_merge`] would change on `existing`, without applying it.
+pub fn preview_merge(
+    existing: &Observation,
+    newer: &Observation,
+    force_newer: bool,
+) -> MergePreview {
+    let merged = compute_merge(existing, newer, force_newer);
+    let mut diffs = Vec::new();
+    push_diff(&mut diffs, "title", &existing.title, &merged.title);
+    push_diff(
+        &mut diffs,
+        "observation_type",
+        &existing.observation_type,
+        &merged.observation_type,
+    );
+    push_diff(&mut diffs, "subtitle", &existing.subtitle, &merged.subtitle);
+    push_diff(
+        &mut diffs,
+        "narrative",
+        &existing.narrative,
+        &merged.narrative,
+    );
+    push_diff(&mut diffs, "facts", &existing.facts, &merged.facts);
+    push_diff(&mut diffs, "keywords", &existing.keywords, &merged.keywords);
+    push_diff(&mut diffs, "concepts", &existing.concepts, &merged.concepts);
+    push_diff(
+        &mut diffs,
+        "files_read",
+        &existing.files_read,
+        &merged.files_read,
+    );
+    push_diff(
+        &mut diffs,
+        "files_modified",
+        &existing.files_modified,
+        &merged.files_modified,
+    );
+    push_diff(
+        &mut diffs,
+        "noise_level",
+        &existing.noise_level,
+        &merged.noise_level,
+    );
+    push_diff(
+        &mut diffs,
+        "noise_reason",
+        &existing.noise_reason,
+        &merged.noise_reason,
+    );
+    push_diff(
+        &mut diffs,
+        "prompt_number",
+        &existing.prompt_number,
+        &merged.prompt_number,
+    );
+    MergePreview {
+        target_id: existing.id.to_string(),
+        diffs,
+    }
+}
+
+fn push_diff<T: Serialize + PartialEq>(
+    diffs: &mut Vec<FieldDiff>,
+    field: &'static str,
+    before: &T,
+    after: &T,
+) {
+    if before != after {
+        diffs.push(FieldDiff {
+            field,
+            before: serde_json::to_value(before).unwrap_or(serde_json::Value::Null),
+            after: serde_json::to_value(after).unwrap_or(serde_json::Value::Null),
+        });
+    }
+}
+
 /// Pick the longer of two optional strings. Prefer `existing` when lengths are equal.
 fn pick_longer_optional(existing: &Option<String>, newer: &Option<String>) -> Option<String> {
     match (existing, newer) {
@@ -214,4 +324,31 @@ mod tests {
         let result = compute_merge(&existing, &newer, false);
         assert_eq!(result.narrative.as_deref(), Some("new text"));
     }
+
+    #[test]
+    fn preview_lists_only_changed_fields() {
+        let mut existing = make_obs("test");
+        existing.narrative = Some("original narrative".to_owned());
+        existing.files_modified = vec!["a.rs".to_owned()];
+        let mut newer = make_obs("test");
+        newer.narrative = Some("rewritten".to_owned());
+        newer.files_modified = vec!["a.rs".to_owned(), "b.rs".to_owned()];
+
+        let preview = preview_merge(&existing, &newer, true);
+        assert_eq!(preview.target_id, "id-test");
+        let files = preview.diff("files_modified").unwrap();
+        assert_eq!(files.after, serde_json::json!(["a.rs", "b.rs"]));
+        let narrative = preview.diff("narrative").unwrap();
+        assert_eq!(narrative.before, serde_json::json!("original narrative"));
+        assert_eq!(narrative.after, serde_json::json!("rewritten"));
+        assert!(preview.diff("title").is_none());
+        assert!(preview.diff("keywords").is_none());
+    }
+
+    #[test]
+    fn preview_of_identical_observation_is_noop() {
+        let existing = make_obs("test");
+        let preview = preview_merge(&existing, &existing.clone(), false);
+        assert!(preview.is_noop());
+    }
 }
@@ -229,6 +229,13 @@ impl NoiseLevel {
             Self::Negligible => "negligible",
         }
     }
+
+    /// Whether observations at this level are transient enough to receive
+    /// the configured default TTL (hidden-by-default levels only).
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(*self, Self::Low | Self::Negligible)
+    }
 }
 
 impl FromStr for NoiseLevel {
@@ -247,3 +254,51 @@ impl FromStr for NoiseLevel {
         }
     }
 }
+
+/// Ordering for observation listings and search results.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ObservationSort {
+    /// Newest first
+    #[default]
+    CreatedDesc,
+    /// Oldest first
+    CreatedAsc,
+    /// Grouped by observation type, newest first within a type
+    Type,
+    /// Most important noise level first, newest first within a level
+    Noise,
+    /// Relevance score; listings without a score fall back to newest first
+    Score,
+}
+
+impl ObservationSort {
+    pub const ALL_VARIANTS_STR: &'static str = "created_desc|created_asc|type|noise|score";
+
+    /// Returns the string representation of the sort order.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::CreatedDesc => "created_desc",
+            Self::CreatedAsc => "created_asc",
+            Self::Type => "type",
+            Self::Noise => "noise",
+            Self::Score => "score",
+        }
+    }
+}
+
+impl FromStr for ObservationSort {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "created_desc" => Ok(Self::CreatedDesc),
+            "created_asc" => Ok(Self::CreatedAsc),
+            "type" => Ok(Self::Type),
+            "noise" => Ok(Self::Noise),
+            "score" => Ok(Self::Score),
+            _ => Err(CoreError::InvalidSort(s.to_owned())),
+        }
+    }
+}
@@ -11,7 +11,8 @@ pub mod error;
 use error::EmbeddingError;
 use fastembed::{InitOptions, TextEmbedding};
 use std::fmt::{Debug, Formatter, Result as FmtResult};
-use std::sync::{Arc, Mutex, Once};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Once, TryLockError};
 
 /// Embedding dimension for `BGE-M3` model (re-exported from core)
 pub use opencode_mem_core::EMBEDDING_DIMENSION;
@@ -77,6 +78,22 @@ fn init_ort(thread_count: usize) {
     });
 }
 
+/// Lock `mutex`, recovering if a previous embedding call panicked while holding it.
+///
+/// Neither the loaded model nor the lazy slot is left half-updated by a panic
+/// mid-inference, so clearing the poison is safe and keeps one bad input from
+/// disabling semantic search for the rest of the process lifetime.
+fn lock_recovering<'a, T>(mutex: &'a Mutex<T>, name: &'static str) -> MutexGuard<'a, T> {
+    mutex.lock().unwrap_or_else(|poisoned| {
+        tracing::warn!(
+            lock = name,
+            "Embedding lock poisoned by a panicked call, clearing poison and continuing"
+        );
+        mutex.clear_poison();
+        poisoned.into_inner()
+    })
+}
+
 impl Debug for EmbeddingService {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("EmbeddingService")
@@ -127,10 +144,7 @@ impl EmbeddingService {
 
 impl EmbeddingProvider for EmbeddingService {
     fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
-        let embeddings = self
-            .model
-            .lock()
-            .map_err(|_| EmbeddingError::LockPoisoned)?
+        let embeddings = lock_recovering(&self.model, "model")
             .embed(vec![text], None)
             .map_err(|e| EmbeddingError::Generation(e.to_string()))?;
         embeddings
@@ -141,10 +155,7 @@ impl EmbeddingProvider for EmbeddingService {
 
     fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
         let texts_vec: Vec<&str> = texts.to_vec();
-        let embeddings = self
-            .model
-            .lock()
-            .map_err(|_| EmbeddingError::LockPoisoned)?
+        let embeddings = lock_recovering(&self.model, "model")
             .embed(texts_vec, None)
             .map_err(|e| EmbeddingError::Generation(e.to_string()))?;
         Ok(embeddings)
@@ -155,6 +166,21 @@ impl EmbeddingProvider for EmbeddingService {
     }
 }
 
+/// Loads the provider behind a [`LazyEmbeddingService`].
+type ProviderLoader =
+    Box<dyn Fn() -> Result<Arc<dyn EmbeddingProvider>, EmbeddingError> + Send + Sync>;
+
+/// Model state of a [`LazyEmbeddingService`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadState {
+    /// Not loaded yet; the first embedding call loads it.
+    Pending,
+    /// An embedding call is loading it right now.
+    Loading,
+    /// Loaded and ready.
+    Loaded,
+}
+
 /// Lazy-loading wrapper around [`EmbeddingService`].
 ///
 /// Defers the expensive ONNX model initialization (~28s) until the first
@@ -165,19 +191,24 @@ impl EmbeddingProvider for EmbeddingService {
 /// (e.g., network issues during model download) are retried on the next call,
 /// rather than permanently caching the error.
 pub struct LazyEmbeddingService {
-    inner: Mutex<Option<Arc<EmbeddingService>>>,
-    thread_count: usize,
+    inner: Mutex<Option<Arc<dyn EmbeddingProvider>>>,
+    /// Set once `inner` holds a provider, so callers can check without
+    /// waiting on the lock an in-progress load holds.
+    loaded: AtomicBool,
+    load: ProviderLoader,
 }
 
 impl Debug for LazyEmbeddingService {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let state = self.inner.lock().map_or("poisoned", |guard| {
-            if guard.is_some() { "ready" } else { "pending" }
-        });
+        let state = match self.inner.try_lock() {
+            Ok(guard) if guard.is_some() => "ready",
+            Ok(_) => "pending",
+            Err(TryLockError::WouldBlock) => "loading",
+            Err(TryLockError::Poisoned(_)) => "poisoned",
+        };
         f.debug_struct("LazyEmbeddingService")
             .field("state", &state)
-            .field("thread_count", &self.thread_count)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -189,9 +220,40 @@ impl LazyEmbeddingService {
             threads = thread_count,
             "Lazy embedding service created (model will load on first use)"
         );
+        Self::with_loader(move || {
+            EmbeddingService::new(thread_count)
+                .map(|svc| Arc::new(svc) as Arc<dyn EmbeddingProvider>)
+        })
+    }
+
+    /// Create a lazy service whose provider comes from `load` instead of the
+    /// `BGE-M3` model, e.g. a stub that fails to load in tests.
+    #[must_use]
+    pub fn with_loader(
+        load: impl Fn() -> Result<Arc<dyn EmbeddingProvider>, EmbeddingError> + Send + Sync + 'static,
+    ) -> Self {
         Self {
             inner: Mutex::new(None),
-            thread_count,
+            loaded: AtomicBool::new(false),
+            load: Box::new(load),
+        }
+    }
+
+    /// Whether the model has been initialized (no load is triggered).
+    #[must_use]
+    pub fn is_loaded(&self) -> bool {
+        self.loaded.load(Ordering::Acquire)
+    }
+
+    /// Current model state. Never blocks, even while a load is in progress.
+    #[must_use]
+    pub fn load_state(&self) -> LoadState {
+        if self.is_loaded() {
+            return LoadState::Loaded;
+        }
+        match self.inner.try_lock() {
+            Err(TryLockError::WouldBlock) => LoadState::Loading,
+            Ok(_) | Err(TryLockError::Poisoned(_)) => LoadState::Pending,
         }
     }
 
@@ -201,20 +263,18 @@ impl LazyEmbeddingService {
     /// so the next call retries initialization.
     fn with_service<T>(
         &self,
-        f: impl FnOnce(&EmbeddingService) -> Result<T, EmbeddingError>,
+        f: impl FnOnce(&dyn EmbeddingProvider) -> Result<T, EmbeddingError>,
     ) -> Result<T, EmbeddingError> {
         let svc = {
-            let mut guard = self
-                .inner
-                .lock()
-                .map_err(|_| EmbeddingError::LockPoisoned)?;
+            let mut guard = lock_recovering(&self.inner, "lazy_init");
             if guard.is_none() {
                 tracing::info!(
                     "First embedding request — initializing model (this may take ~30s)..."
                 );
-                match EmbeddingService::new(self.thread_count) {
+                match (self.load)() {
                     Ok(svc) => {
-                        *guard = Some(Arc::new(svc));
+                        *guard = Some(svc);
+                        self.loaded.store(true, Ordering::Release);
                     }
                     Err(e) => {
                         tracing::warn!(error = %e, "Embedding model init failed (will retry on next call)");
@@ -225,7 +285,7 @@ impl LazyEmbeddingService {
             Arc::clone(guard.as_ref().expect("just initialized"))
         };
 
-        f(&svc)
+        f(svc.as_ref())
     }
 }
 
@@ -256,4 +316,65 @@ mod tests {
         let service = EmbeddingService::new(1).expect("Failed to create service");
         assert_eq!(service.dimension(), EMBEDDING_DIMENSION);
     }
+
+    /// Poison `mutex` the way a panicking embedding call would.
+    fn poison<T: Send>(mutex: &Mutex<T>) {
+        std::thread::scope(|s| {
+            let _ = s
+                .spawn(|| {
+                    let _guard = mutex.lock();
+                    panic!("simulated panic while holding embedding lock");
+                })
+                .join();
+        });
+    }
+
+    #[test]
+    fn test_poisoned_lock_is_recovered() {
+        let mutex = Mutex::new(vec![0.5_f32]);
+        poison(&mutex);
+        assert!(mutex.is_poisoned());
+
+        assert_eq!(*lock_recovering(&mutex, "test"), vec![0.5_f32]);
+        assert!(
+            !mutex.is_poisoned(),
+            "poison should be cleared after recovery"
+        );
+        assert!(mutex.lock().is_ok());
+    }
+
+    #[test]
+    fn test_load_state_does_not_wait_for_loading_model() {
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let (finish_tx, finish_rx) = std::sync::mpsc::channel::<()>();
+        let finish_rx = Mutex::new(finish_rx);
+        let lazy = LazyEmbeddingService::with_loader(move || {
+            started_tx.send(()).ok();
+            lock_recovering(&finish_rx, "test").recv().ok();
+            Err(EmbeddingError::ModelInit("stub".to_owned()))
+        });
+        assert_eq!(lazy.load_state(), LoadState::Pending);
+
+        std::thread::scope(|s| {
+            let loading = s.spawn(|| lazy.embed("text"));
+            started_rx.recv().unwrap();
+            assert_eq!(lazy.load_state(), LoadState::Loading);
+            assert!(!lazy.is_loaded());
+            finish_tx.send(()).unwrap();
+            assert!(loading.join().unwrap().is_err());
+        });
+        assert_eq!(lazy.load_state(), LoadState::Pending);
+    }
+
+    #[test]
+    fn test_lazy_service_recovers_poisoned_slot() {
+        let lazy = LazyEmbeddingService::with_loader(|| Err(EmbeddingError::EmptyResult));
+        poison(&lazy.inner);
+        assert!(format!("{lazy:?}").contains("poisoned"));
+
+        // The slot stays usable: a subsequent call would retry initialization
+        // instead of failing with `LockPoisoned` forever.
+        assert!(lock_recovering(&lazy.inner, "lazy_init").is_none());
+        assert!(format!("{lazy:?}").contains("pending"));
+    }
 }
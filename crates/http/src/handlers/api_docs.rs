@@ -32,6 +32,67 @@ pub fn get_search_help() -> SearchHelpResponse {
                     },
                 ],
             },
+            EndpointDoc {
+                path: "/api/search/full",
+                method: "GET",
+                description: "Search and return full observations in rank order (single round-trip)",
+                params: vec![
+                    ParamDoc {
+                        name: "q",
+                        required: true,
+                        description: "Search query",
+                    },
+                    ParamDoc {
+                        name: "limit",
+                        required: false,
+                        description: "Max results (default 20)",
+                    },
+                    ParamDoc {
+                        name: "project",
+                        required: false,
+                        description: "Filter by project",
+                    },
+                ],
+            },
+            EndpointDoc {
+                path: "/api/search/by-keyword",
+                method: "GET",
+                description: "Observations tagged with an exact keyword, ranked by keyword prominence and recency",
+                params: vec![
+                    ParamDoc {
+                        name: "keyword",
+                        required: true,
+                        description: "Keyword to match exactly",
+                    },
+                    ParamDoc {
+                        name: "limit",
+                        required: false,
+                        description: "Max results (default 20)",
+                    },
+                ],
+            },
+            EndpointDoc {
+                path: "/api/search/by-concept",
+                method: "GET",
+                description: "Observations tagged with a concept, newest first",
+                params: vec![
+                    ParamDoc {
+                        name: "concept",
+                        required: true,
+                        description: "how-it-works|why-it-exists|what-changed|problem-solution|gotcha|pattern|trade-off",
+                    },
+                    ParamDoc {
+                        name: "project",
+                        required: false,
+                        description: "Filter by project",
+                    },
+                    ParamDoc {
+                        name: "limit",
+                        required: false,
+                        description: "Max results (default 20)",
+                    },
+                ],
+            },
             EndpointDoc {
                 path: "/api/unified-timeline",
                 method: "GET",
@@ -1,7 +1,7 @@
 use crate::api_error::{ApiError, DegradedExt, OrDegraded};
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     response::sse::{Event, Sse},
 };
 use chrono::{Datelike, Utc};
@@ -13,12 +13,12 @@ use std::sync::Arc;
 use tokio::sync::broadcast::error::RecvError;
 
 use opencode_mem_core::{GlobalKnowledge, Observation, SearchResult};
-use opencode_mem_service::StorageStats;
+use opencode_mem_service::{ProjectFileActivity, StorageStats};
 
 use crate::AppState;
 use crate::api_types::{
-    ContextInjectResponse, ContextPreview, ContextPreviewQuery, ContextQuery, SearchHelpResponse,
-    SearchQuery, TimelineResult, UnifiedTimelineQuery,
+    ContextInjectResponse, ContextPreview, ContextPreviewQuery, ContextQuery, ProjectFilesQuery,
+    SearchHelpResponse, SearchQuery, TimelineResult, UnifiedTimelineQuery,
 };
 
 use super::api_docs::get_search_help;
@@ -36,7 +36,7 @@ pub async fn get_context_recent(
     };
     let observations = state
         .search_service
-        .get_context_for_project(&query.project, query.limit)
+        .get_context_for_project(&query.project, query.capped_limit())
         .await
         .or_degraded(degraded_fallback)?;
 
@@ -508,6 +508,20 @@ pub async fn get_projects(
         .map(Json)
 }
 
+/// Files read or modified across a project's observations, newest activity first.
+pub async fn get_project_files(
+    State(state): State<Arc<AppState>>,
+    Path(project): Path<String>,
+    Query(query): Query<ProjectFilesQuery>,
+) -> Result<Json<Vec<ProjectFileActivity>>, ApiError> {
+    state
+        .search_service
+        .get_project_files(&project, query.capped_limit())
+        .await
+        .or_degraded(Vec::<ProjectFileActivity>::new())
+        .map(Json)
+}
+
 pub async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<StorageStats>, ApiError> {
     state
         .search_service
@@ -539,11 +553,7 @@ pub async fn get_decisions(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SearchResult>>, ApiError> {
-    let q = if query.q.is_empty() {
-        None
-    } else {
-        Some(query.q.as_str())
-    };
+    let q = opencode_mem_core::normalize_query(&query.q);
     state
         .search_service
         .search_with_filters(
@@ -563,11 +573,7 @@ pub async fn get_changes(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SearchResult>>, ApiError> {
-    let q = if query.q.is_empty() {
-        None
-    } else {
-        Some(query.q.as_str())
-    };
+    let q = opencode_mem_core::normalize_query(&query.q);
     state
         .search_service
         .search_with_filters(
@@ -587,10 +593,9 @@ pub async fn get_how_it_works(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SearchResult>>, ApiError> {
-    let search_query = if query.q.is_empty() {
-        "how-it-works".to_owned()
-    } else {
-        format!("{} how-it-works", query.q)
+    let search_query = match opencode_mem_core::normalize_query(&query.q) {
+        Some(q) => format!("{q} how-it-works"),
+        None => "how-it-works".to_owned(),
     };
     state
         .search_service
@@ -613,7 +618,7 @@ pub async fn context_preview(
 ) -> Result<Json<ContextPreview>, ApiError> {
     let observations = state
         .search_service
-        .get_context_for_project(&query.project, query.limit)
+        .get_context_for_project(&query.project, query.capped_limit())
         .await
         .or_degraded(Vec::<Observation>::new())?;
 
@@ -55,6 +55,17 @@ pub async fn start_cron_scheduler(state: Arc<AppState>) {
                     Ok(_) => {}
                     Err(e) => tracing::warn!("Cron: embedding backfill failed: {}", e),
                 }
+                match state_clone
+                    .knowledge_service
+                    .run_embedding_backfill(100)
+                    .await
+                {
+                    Ok(generated) if generated > 0 => {
+                        tracing::info!("Cron: generated {} knowledge embeddings", generated);
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Cron: knowledge embedding backfill failed: {}", e),
+                }
             });
         }
 
@@ -84,6 +95,19 @@ pub async fn start_cron_scheduler(state: Arc<AppState>) {
             });
         }
 
+        if loop_count.is_multiple_of(720) {
+            let state_clone = Arc::clone(&state);
+            state.background_tasks.lock().await.spawn(async move {
+                match state_clone.observation_service.expire_observations().await {
+                    Ok(expired) if expired > 0 => {
+                        tracing::info!(expired, "Cron: observation expiry sweep completed");
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!(error = %e, "Cron: observation expiry sweep failed"),
+                }
+            });
+        }
+
         if loop_count.is_multiple_of(17280) {
             let ttl_secs = state.config.dlq_ttl_secs();
             let state_clone = Arc::clone(&state);
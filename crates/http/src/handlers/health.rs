@@ -0,0 +1,139 @@
+//! Per-subsystem health probes for `/api/health/detailed`.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::{Json, extract::State};
+use opencode_mem_service::LoadState;
+
+use crate::AppState;
+use crate::api_types::{CircuitBreakerHealth, DetailedHealthResponse, SubsystemHealth};
+
+/// Upper bound for any single probe so one hung dependency can't stall the endpoint.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl SubsystemHealth {
+    fn disabled(detail: &str) -> Self {
+        Self {
+            status: "disabled",
+            latency_ms: None,
+            detail: Some(detail.to_owned()),
+        }
+    }
+
+    fn is_degraded(&self) -> bool {
+        self.status == "degraded"
+    }
+}
+
+/// Run `probe` under [`PROBE_TIMEOUT`] and record its latency.
+async fn timed_probe<E, F>(probe: F) -> SubsystemHealth
+where
+    E: std::fmt::Display,
+    F: Future<Output = Result<(), E>>,
+{
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(PROBE_TIMEOUT, probe).await;
+    let latency_ms = Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX));
+    match outcome {
+        Ok(Ok(())) => SubsystemHealth {
+            status: "ok",
+            latency_ms,
+            detail: None,
+        },
+        Ok(Err(e)) => SubsystemHealth {
+            status: "degraded",
+            latency_ms,
+            detail: Some(e.to_string()),
+        },
+        Err(_) => SubsystemHealth {
+            status: "degraded",
+            latency_ms,
+            detail: Some(format!(
+                "probe timed out after {}s",
+                PROBE_TIMEOUT.as_secs()
+            )),
+        },
+    }
+}
+
+async fn embeddings_health(state: &AppState) -> SubsystemHealth {
+    match state.search_service.embeddings_state() {
+        None => SubsystemHealth::disabled("OPENCODE_MEM_DISABLE_EMBEDDINGS is set"),
+        // Probing an unloaded model would trigger the full (~30s) model load.
+        Some(LoadState::Pending) => SubsystemHealth {
+            status: "ok",
+            latency_ms: None,
+            detail: Some("model loads on first use".to_owned()),
+        },
+        Some(LoadState::Loading) => SubsystemHealth {
+            status: "loading",
+            latency_ms: None,
+            detail: Some("model is loading".to_owned()),
+        },
+        Some(LoadState::Loaded) => timed_probe(state.search_service.probe_embeddings()).await,
+    }
+}
+
+async fn infinite_memory_health(state: &AppState) -> SubsystemHealth {
+    match state.infinite_mem {
+        None => SubsystemHealth::disabled("infinite memory not configured"),
+        Some(ref mem) => timed_probe(mem.ping()).await,
+    }
+}
+
+fn llm_circuit_breaker_health(state: &AppState) -> CircuitBreakerHealth {
+    let cb = state.observation_service.llm_circuit_breaker();
+    CircuitBreakerHealth {
+        state: cb.state_name(),
+        consecutive_failures: cb.consecutive_failures(),
+        seconds_until_probe: cb.is_open().then(|| cb.seconds_until_probe()),
+    }
+}
+
+pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<DetailedHealthResponse> {
+    let (storage, llm, embeddings, infinite_memory) = tokio::join!(
+        timed_probe(state.search_service.ping_storage()),
+        timed_probe(state.observation_service.probe_llm()),
+        embeddings_health(&state),
+        infinite_memory_health(&state),
+    );
+
+    let llm_circuit_breaker = llm_circuit_breaker_health(&state);
+
+    let degraded = [&storage, &llm, &embeddings, &infinite_memory]
+        .iter()
+        .any(|s| s.is_degraded())
+        || llm_circuit_breaker.state == "open";
+
+    Json(DetailedHealthResponse {
+        status: if degraded { "degraded" } else { "ok" },
+        storage,
+        llm,
+        llm_circuit_breaker,
+        embeddings,
+        infinite_memory,
+        uptime_seconds: state.started_at.elapsed().as_secs(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn timed_probe_reports_ok_with_latency() {
+        let health = timed_probe(async { Ok::<(), String>(()) }).await;
+        assert_eq!(health.status, "ok");
+        assert!(health.latency_ms.is_some());
+        assert!(health.detail.is_none());
+    }
+
+    #[tokio::test]
+    async fn timed_probe_reports_failure_as_degraded() {
+        let health = timed_probe(async { Err::<(), _>("connection refused") }).await;
+        assert!(health.is_degraded());
+        assert_eq!(health.detail.as_deref(), Some("connection refused"));
+    }
+}
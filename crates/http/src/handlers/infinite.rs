@@ -111,7 +111,7 @@ pub async fn infinite_search_entities(
     let infinite_mem = require_infinite_mem(&state)?;
 
     infinite_mem
-        .search_by_entity(&query.entity_type, &query.value, query.limit)
+        .search_by_entity(&query.entity_type, &query.value, query.capped_limit())
         .await
         .map(Json)
         .map_err(|e| {
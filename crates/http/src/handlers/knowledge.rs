@@ -18,7 +18,7 @@ pub async fn list_knowledge(
 ) -> Result<Json<Vec<GlobalKnowledge>>, ApiError> {
     state
         .knowledge_service
-        .list_knowledge(query.knowledge_type, query.limit)
+        .list_knowledge(query.knowledge_type, query.capped_limit())
         .await
         .or_degraded(Vec::<GlobalKnowledge>::new())
         .map(Json)
@@ -33,7 +33,7 @@ pub async fn search_knowledge(
     }
     let results = state
         .knowledge_service
-        .search_knowledge(&query.q, query.limit)
+        .search_knowledge(&query.q, query.capped_limit())
         .await
         .or_degraded(Vec::<KnowledgeSearchResult>::new())?;
 
@@ -31,11 +31,77 @@ pub(crate) fn check_admin_access(
     false
 }
 
+/// Parse an optional `?sort=` value, rejecting unknown orders with 400.
+pub(crate) fn parse_sort(
+    raw: Option<&str>,
+    default: opencode_mem_core::ObservationSort,
+) -> Result<opencode_mem_core::ObservationSort, crate::api_error::ApiError> {
+    match raw {
+        Some(s) if !s.trim().is_empty() => s.parse().map_err(|e: opencode_mem_core::CoreError| {
+            crate::api_error::ApiError::BadRequest(e.to_string())
+        }),
+        _ => Ok(default),
+    }
+}
+
+/// Header callers use to select a non-default LLM model for a single request.
+pub(crate) const MODEL_OVERRIDE_HEADER: &str = "x-model";
+
+/// Read the per-request model override from the `X-Model` header.
+///
+/// Returns `Ok(None)` when the header is absent or blank, and rejects models
+/// that are not listed in `OPENCODE_MEM_ALLOWED_MODELS`.
+pub(crate) fn requested_model(
+    headers: &axum::http::HeaderMap,
+    config: &opencode_mem_core::AppConfig,
+) -> Result<Option<String>, crate::api_error::ApiError> {
+    let Some(raw) = headers.get(MODEL_OVERRIDE_HEADER) else {
+        return Ok(None);
+    };
+    let model = raw
+        .to_str()
+        .map_err(|_| crate::api_error::ApiError::BadRequest("Invalid X-Model header".into()))?
+        .trim();
+    if model.is_empty() {
+        return Ok(None);
+    }
+    if !config.is_model_allowed(model) {
+        tracing::warn!(model = %model, "Rejected X-Model override not in allowlist");
+        return Err(crate::api_error::ApiError::BadRequest(format!(
+            "model not allowed: {model}"
+        )));
+    }
+    Ok(Some(model.to_owned()))
+}
+
+/// Observation service for this request, using the overridden model if one was given.
+pub(crate) fn observation_service_for(
+    state: &crate::AppState,
+    model: Option<String>,
+) -> std::sync::Arc<opencode_mem_service::ObservationService> {
+    match model {
+        Some(m) => std::sync::Arc::new(state.observation_service.with_llm_model(m)),
+        None => std::sync::Arc::clone(&state.observation_service),
+    }
+}
+
+/// Session service for this request, using the overridden model if one was given.
+pub(crate) fn session_service_for(
+    state: &crate::AppState,
+    model: Option<String>,
+) -> std::sync::Arc<opencode_mem_service::SessionService> {
+    match model {
+        Some(m) => std::sync::Arc::new(state.session_service.with_llm_model(m)),
+        None => std::sync::Arc::clone(&state.session_service),
+    }
+}
+
 pub mod admin;
 pub mod api_docs;
 pub mod branch;
 pub mod context;
 pub(crate) mod cron;
+pub mod health;
 pub mod infinite;
 pub mod knowledge;
 pub mod observations;
@@ -45,3 +111,53 @@ pub mod search;
 pub(crate) mod session_ops;
 pub mod sessions;
 pub mod sessions_api;
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue};
+    use opencode_mem_core::AppConfig;
+
+    use super::{MODEL_OVERRIDE_HEADER, requested_model};
+    use crate::api_error::ApiError;
+    use crate::test_support::test_config;
+
+    fn config_allowing(models: &[&str]) -> AppConfig {
+        AppConfig {
+            allowed_models: models.iter().map(|m| (*m).to_owned()).collect(),
+            ..test_config()
+        }
+    }
+
+    fn headers_with_model(model: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(MODEL_OVERRIDE_HEADER, HeaderValue::from_str(model).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_requested_model_accepts_allowlisted_model() {
+        let config = config_allowing(&["fast-model", "smart-model"]);
+        let model = requested_model(&headers_with_model(" smart-model "), &config).unwrap();
+        assert_eq!(model.as_deref(), Some("smart-model"));
+    }
+
+    #[test]
+    fn test_requested_model_rejects_model_outside_allowlist() {
+        let config = config_allowing(&["fast-model"]);
+        let err = requested_model(&headers_with_model("expensive-model"), &config).unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(ref msg) if msg.contains("expensive-model")));
+
+        let empty_allowlist = config_allowing(&[]);
+        assert!(requested_model(&headers_with_model("fast-model"), &empty_allowlist).is_err());
+    }
+
+    #[test]
+    fn test_requested_model_absent_or_blank_header_uses_default() {
+        let config = config_allowing(&["fast-model"]);
+        assert_eq!(requested_model(&HeaderMap::new(), &config).unwrap(), None);
+        assert_eq!(
+            requested_model(&headers_with_model("  "), &config).unwrap(),
+            None
+        );
+    }
+}
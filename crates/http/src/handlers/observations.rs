@@ -2,7 +2,7 @@ use crate::api_error::{ApiError, DegradedExt, OrDegraded};
 use axum::{
     Json,
     extract::{ConnectInfo, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
 };
 use serde_json::json;
 use std::net::SocketAddr;
@@ -10,23 +10,27 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use opencode_mem_core::{
-    NoiseLevel, Observation, ObservationType, SearchResult, SessionSummary, ToolCall, UserPrompt,
+    CommitHookRequest, NoiseLevel, Observation, ObservationSort, ObservationType, SearchResult,
+    SessionSummary, ToolCall, UserPrompt,
 };
 use opencode_mem_service::{PaginatedResult, QueueToolCallResult};
 
 use crate::AppState;
 use crate::api_types::{
-    BatchRequest, ObserveBatchResponse, ObserveResponse, PaginationQuery, SaveMemoryRequest,
-    SearchQuery, TimelineQuery,
+    BatchRequest, DirectObservationRequest, ObservationCountQuery, ObservationCountResponse,
+    ObservationListQuery, ObserveBatchResponse, ObserveResponse, PaginationQuery,
+    SaveMemoryRequest, SearchQuery, TimelineQuery,
 };
 
 pub async fn observe(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(tool_call): Json<ToolCall>,
 ) -> Result<Json<ObserveResponse>, ApiError> {
+    let model = super::requested_model(&headers, &state.config)?;
     match state
         .queue_service
-        .queue_tool_call(&tool_call)
+        .queue_tool_call(&tool_call, model.as_deref())
         .await
         .map_err(|e| {
             tracing::error!("Queue message error: {}", e);
@@ -73,8 +77,11 @@ pub async fn observe_batch(
 
 pub async fn save_memory(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(req): Json<SaveMemoryRequest>,
 ) -> Result<(StatusCode, Json<Observation>), ApiError> {
+    let observation_service =
+        super::observation_service_for(&state, super::requested_model(&headers, &state.config)?);
     let text = req.text.trim();
     if text.is_empty() {
         return Err(ApiError::BadRequest("Bad Request".into()));
@@ -116,8 +123,7 @@ pub async fn save_memory(
 
     let id = uuid::Uuid::new_v4().to_string();
 
-    match state
-        .observation_service
+    match observation_service
         .save_memory_with_id(
             &id,
             text,
@@ -162,6 +168,118 @@ pub async fn save_memory(
     }
 }
 
+/// Stores a fully-formed observation (e.g. from CI) without LLM compression.
+pub async fn save_direct_observation(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<DirectObservationRequest>,
+) -> Result<(StatusCode, Json<Observation>), ApiError> {
+    let raw_type = req.observation_type.trim();
+    let observation_type = ObservationType::from_str(raw_type).map_err(|_| {
+        ApiError::BadRequest(format!(
+            "invalid observation_type: {raw_type} (allowed: {})",
+            ObservationType::ALL_VARIANTS_STR
+        ))
+    })?;
+    let noise_level = match req
+        .noise_level
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        Some(raw) => NoiseLevel::from_str(raw).map_err(|_| {
+            ApiError::BadRequest(format!(
+                "invalid noise_level: {raw} (allowed: {})",
+                NoiseLevel::ALL_VARIANTS_STR
+            ))
+        })?,
+        None => NoiseLevel::Medium,
+    };
+    let title = opencode_mem_core::sanitize_input(req.title.trim());
+    if title.is_empty() {
+        return Err(ApiError::BadRequest("title is required".into()));
+    }
+    let sanitize_all = |items: Vec<String>| -> Vec<String> {
+        items
+            .into_iter()
+            .map(|s| opencode_mem_core::sanitize_input(s.trim()))
+            .filter(|s| !s.is_empty())
+            .collect()
+    };
+
+    let obs = Observation::builder(
+        uuid::Uuid::new_v4().to_string(),
+        "direct".to_owned(),
+        observation_type,
+        title,
+    )
+    .maybe_project(
+        req.project
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(Into::into),
+    )
+    .maybe_subtitle(
+        req.subtitle
+            .as_deref()
+            .map(|s| opencode_mem_core::sanitize_input(s.trim()))
+            .filter(|s| !s.is_empty()),
+    )
+    .maybe_narrative(
+        req.narrative
+            .as_deref()
+            .map(|s| opencode_mem_core::sanitize_input(s.trim()))
+            .filter(|s| !s.is_empty()),
+    )
+    .facts(sanitize_all(req.facts))
+    .files_read(sanitize_all(req.files_read))
+    .files_modified(sanitize_all(req.files_modified))
+    .keywords(sanitize_all(req.keywords))
+    .noise_level(noise_level)
+    .build();
+
+    match state
+        .observation_service
+        .save_direct(obs)
+        .await
+        .map_err(|e| {
+            tracing::error!("Direct observation error: {}", e);
+            ApiError::from(e)
+        })? {
+        opencode_mem_service::SaveMemoryResult::Created(obs) => {
+            Ok((StatusCode::CREATED, Json(obs)))
+        }
+        opencode_mem_service::SaveMemoryResult::Duplicate(obs) => Ok((StatusCode::OK, Json(obs))),
+        opencode_mem_service::SaveMemoryResult::Filtered => {
+            Err(ApiError::UnprocessableEntity("Unprocessable Entity".into()))
+        }
+    }
+}
+
+/// Records a git commit posted by `opencode-mem hook commit` (typically from a
+/// `post-commit` hook) as a `change` observation.
+pub async fn record_commit(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<CommitHookRequest>,
+) -> Result<(StatusCode, Json<Observation>), ApiError> {
+    match state
+        .observation_service
+        .record_commit(&req)
+        .await
+        .map_err(|e| {
+            tracing::error!("Record commit error: {}", e);
+            ApiError::from(e)
+        })? {
+        opencode_mem_service::SaveMemoryResult::Created(obs) => {
+            Ok((StatusCode::CREATED, Json(obs)))
+        }
+        opencode_mem_service::SaveMemoryResult::Duplicate(obs) => Ok((StatusCode::OK, Json(obs))),
+        opencode_mem_service::SaveMemoryResult::Filtered => {
+            Err(ApiError::UnprocessableEntity("Unprocessable Entity".into()))
+        }
+    }
+}
+
 pub async fn get_observation(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -180,7 +298,7 @@ pub async fn get_recent(
 ) -> Result<Json<Vec<Observation>>, ApiError> {
     state
         .search_service
-        .get_recent_observations(query.capped_limit())
+        .get_recent_observations(None, query.capped_limit())
         .await
         .or_degraded(Vec::<Observation>::new())
         .map(Json)
@@ -220,16 +338,40 @@ pub async fn get_observations_batch(
 
 pub async fn get_observations_paginated(
     State(state): State<Arc<AppState>>,
-    Query(query): Query<PaginationQuery>,
+    Query(query): Query<ObservationListQuery>,
 ) -> Result<Json<PaginatedResult<Observation>>, ApiError> {
+    let sort = super::parse_sort(query.sort.as_deref(), ObservationSort::CreatedDesc)?;
     state
         .search_service
-        .get_observations_paginated(query.offset, query.capped_limit(), query.project.as_deref())
+        .get_observations_paginated(
+            query.offset,
+            query.capped_limit(),
+            query.project.as_deref(),
+            sort,
+        )
         .await
         .or_degraded(PaginatedResult::<Observation>::empty())
         .map(Json)
 }
 
+pub async fn count_observations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ObservationCountQuery>,
+) -> Result<Json<ObservationCountResponse>, ApiError> {
+    state
+        .search_service
+        .count_observations(
+            query.project.as_deref(),
+            query.obs_type.as_deref(),
+            query.from.as_deref(),
+            query.to.as_deref(),
+        )
+        .await
+        .map(|count| ObservationCountResponse { count })
+        .or_degraded(ObservationCountResponse { count: 0 })
+        .map(Json)
+}
+
 pub async fn get_summaries_paginated(
     State(state): State<Arc<AppState>>,
     Query(query): Query<PaginationQuery>,
@@ -278,6 +420,26 @@ pub async fn get_prompt_by_id(
         .map(Json)
 }
 
+pub async fn get_prompt_observations(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<Observation>>, ApiError> {
+    let prompt = state
+        .search_service
+        .get_prompt_by_id(&id)
+        .await
+        .or_degraded(None::<UserPrompt>)?;
+    if prompt.is_none() {
+        return Err(ApiError::NotFound(format!("prompt '{id}' not found")));
+    }
+    state
+        .search_service
+        .get_prompt_observations(&id)
+        .await
+        .or_degraded(Vec::<Observation>::new())
+        .map(Json)
+}
+
 pub async fn delete_observation(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: axum::http::HeaderMap,
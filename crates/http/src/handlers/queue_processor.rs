@@ -2,7 +2,7 @@ use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
 use opencode_mem_core::ToolCall;
-use opencode_mem_service::{PendingMessage, QueueService};
+use opencode_mem_service::{PendingMessage, QueueService, ServiceError};
 
 use crate::AppState;
 
@@ -10,7 +10,10 @@ pub(crate) fn max_queue_workers(state: &AppState) -> usize {
     state.config.queue_workers
 }
 
-pub async fn process_pending_message(state: &AppState, msg: &PendingMessage) -> anyhow::Result<()> {
+pub async fn process_pending_message(
+    state: &AppState,
+    msg: &PendingMessage,
+) -> Result<(), ServiceError> {
     if state
         .queue_service
         .should_skip_project(msg.project.as_deref())
@@ -73,7 +76,14 @@ pub async fn process_pending_message(state: &AppState, msg: &PendingMessage) ->
         tool_response.to_owned(),
     );
 
-    let result = state.observation_service.process(&id, tool_call).await?;
+    // Re-check the allowlist: it may have changed since the message was queued.
+    let model = msg
+        .model
+        .clone()
+        .filter(|m| state.config.is_model_allowed(m));
+    let result = super::observation_service_for(state, model)
+        .process(&id, tool_call)
+        .await?;
 
     if let Some(observation) = result {
         tracing::info!(
@@ -144,6 +154,28 @@ pub async fn start_queue_poller(state: Arc<AppState>) {
             continue;
         }
 
+        // While the LLM circuit breaker is open every claimed message would
+        // fail fast; leave them in the queue until a probe is admitted.
+        if let Some(wait) = state
+            .observation_service
+            .llm_circuit_breaker()
+            .retry_after()
+        {
+            drop(permits);
+            tracing::debug!(
+                wait_secs = wait.as_secs(),
+                "Background processor: LLM circuit open, pausing claims"
+            );
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {},
+                _ = shutdown_rx.recv() => {
+                    tracing::info!("Queue poller: shutting down");
+                    return;
+                }
+            }
+            continue;
+        }
+
         let claim_limit = permits.len();
         let messages = match state
             .queue_service
@@ -207,11 +239,26 @@ pub async fn start_queue_poller(state: Arc<AppState>) {
                             }
                         }
                         Err(e) => {
-                            tracing::error!("Background: process message {} failed: {}", msg.id, e);
-                            if let Err(e) =
-                                state_clone.queue_service.fail_message(msg.id, false).await
+                            match state_clone
+                                .queue_service
+                                .fail_or_defer_message(msg.id, &e)
+                                .await
                             {
-                                tracing::error!("Background: fail message {} error: {}", msg.id, e);
+                                Ok(true) => tracing::debug!(
+                                    "Background: message {} deferred: {}",
+                                    msg.id,
+                                    e
+                                ),
+                                Ok(false) => tracing::error!(
+                                    "Background: process message {} failed: {}",
+                                    msg.id,
+                                    e
+                                ),
+                                Err(err) => tracing::error!(
+                                    "Background: fail message {} error: {}",
+                                    msg.id,
+                                    err
+                                ),
                             }
                         }
                     }
@@ -3,24 +3,26 @@ use axum::{
     Json,
     extract::{Query, State},
 };
+use std::str::FromStr;
 use std::sync::Arc;
 
-use opencode_mem_core::{SearchResult, SessionSummary, UserPrompt};
+use opencode_mem_core::{
+    Concept, Observation, ObservationSort, SearchResult, SessionSummary, UserPrompt,
+    normalize_query,
+};
 
 use crate::AppState;
-use crate::api_types::{FileSearchQuery, SearchQuery};
+use crate::api_types::{ConceptSearchQuery, FileSearchQuery, KeywordSearchQuery, SearchQuery};
+use crate::handlers::parse_sort;
 
 pub async fn search(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SearchResult>>, ApiError> {
-    let q = if query.q.is_empty() {
-        None
-    } else {
-        Some(query.q.as_str())
-    };
+    let q = normalize_query(&query.q);
+    let sort = parse_sort(query.sort.as_deref(), ObservationSort::Score)?;
 
-    state
+    let results = state
         .search_service
         .smart_search(
             q,
@@ -30,23 +32,45 @@ pub async fn search(
             query.to.as_deref(),
             query.capped_limit(),
         )
-        .await
-        .or_degraded(Vec::<SearchResult>::new())
-        .map(Json)
+        .await;
+    let sorted = match results {
+        Ok(results) => state.search_service.sort_results(results, sort).await,
+        Err(e) => Err(e),
+    };
+    sorted.or_degraded(Vec::<SearchResult>::new()).map(Json)
 }
 
 pub async fn hybrid_search(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SearchResult>>, ApiError> {
-    if query.q.is_empty() {
+    let sort = parse_sort(query.sort.as_deref(), ObservationSort::Score)?;
+    let Some(q) = normalize_query(&query.q) else {
         return Ok(Json(Vec::new()));
-    }
+    };
+    let results = state
+        .search_service
+        .hybrid_search(q, query.capped_limit())
+        .await;
+    let sorted = match results {
+        Ok(results) => state.search_service.sort_results(results, sort).await,
+        Err(e) => Err(e),
+    };
+    sorted.or_degraded(Vec::<SearchResult>::new()).map(Json)
+}
+
+pub async fn search_full(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<Vec<Observation>>, ApiError> {
+    let Some(q) = normalize_query(&query.q) else {
+        return Ok(Json(Vec::new()));
+    };
     state
         .search_service
-        .hybrid_search(&query.q, query.capped_limit())
+        .search_full(q, query.project.as_deref(), query.capped_limit())
         .await
-        .or_degraded(Vec::<SearchResult>::new())
+        .or_degraded(Vec::<Observation>::new())
         .map(Json)
 }
 
@@ -54,13 +78,13 @@ pub async fn semantic_search(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SearchResult>>, ApiError> {
-    if query.q.is_empty() {
+    let Some(q) = normalize_query(&query.q) else {
         return Ok(Json(Vec::new()));
-    }
+    };
 
     state
         .search_service
-        .semantic_search_with_fallback(&query.q, query.capped_limit())
+        .semantic_search_with_fallback(q, query.capped_limit())
         .await
         .or_degraded(Vec::<SearchResult>::new())
         .map(Json)
@@ -70,12 +94,12 @@ pub async fn search_sessions(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<SessionSummary>>, ApiError> {
-    if query.q.is_empty() {
+    let Some(q) = normalize_query(&query.q) else {
         return Ok(Json(Vec::new()));
-    }
+    };
     state
         .search_service
-        .search_sessions(&query.q, query.capped_limit())
+        .search_sessions(q, query.capped_limit())
         .await
         .or_degraded(Vec::<SessionSummary>::new())
         .map(Json)
@@ -85,12 +109,12 @@ pub async fn search_prompts(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<UserPrompt>>, ApiError> {
-    if query.q.is_empty() {
+    let Some(q) = normalize_query(&query.q) else {
         return Ok(Json(Vec::new()));
-    }
+    };
     state
         .search_service
-        .search_prompts(&query.q, query.capped_limit())
+        .search_prompts(q, query.capped_limit())
         .await
         .or_degraded(Vec::<UserPrompt>::new())
         .map(Json)
@@ -107,3 +131,34 @@ pub async fn search_by_file(
         .or_degraded(Vec::<SearchResult>::new())
         .map(Json)
 }
+
+pub async fn search_by_keyword(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<KeywordSearchQuery>,
+) -> Result<Json<Vec<SearchResult>>, ApiError> {
+    state
+        .search_service
+        .search_by_keyword(&query.keyword, query.capped_limit())
+        .await
+        .or_degraded(Vec::<SearchResult>::new())
+        .map(Json)
+}
+
+pub async fn search_by_concept(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ConceptSearchQuery>,
+) -> Result<Json<Vec<SearchResult>>, ApiError> {
+    let concept = Concept::from_str(&query.concept).map_err(|_| {
+        ApiError::BadRequest(format!(
+            "Invalid concept '{}'. Expected one of: {}",
+            query.concept,
+            Concept::ALL_VARIANTS_STR
+        ))
+    })?;
+    state
+        .search_service
+        .search_by_concept(concept, query.project.as_deref(), query.capped_limit())
+        .await
+        .or_degraded(Vec::<SearchResult>::new())
+        .map(Json)
+}
@@ -17,6 +17,7 @@ pub(crate) async fn create_session(
     content_session_id: String,
     project: Option<String>,
     user_prompt: Option<String>,
+    prompt_number: Option<u32>,
 ) -> Result<SessionInitResponse, ApiError> {
     let session = Session::new(
         opencode_mem_core::SessionId(session_db_id.clone()),
@@ -33,7 +34,7 @@ pub(crate) async fn create_session(
     );
     state
         .session_service
-        .init_session(session)
+        .init_session(session, prompt_number)
         .await
         .map_err(|e| {
             tracing::error!("Session init failed: {}", e);
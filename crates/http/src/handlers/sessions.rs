@@ -2,6 +2,7 @@ use crate::api_error::{ApiError, DegradedExt, OrDegraded};
 use axum::{
     Json,
     extract::{Path, State},
+    http::HeaderMap,
 };
 use serde_json::json;
 use std::sync::Arc;
@@ -18,11 +19,13 @@ use super::session_ops::{create_session, enqueue_session_observations};
 
 pub async fn generate_summary(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(req): Json<SessionSummaryRequest>,
 ) -> Result<Json<serde_json::Value>, ApiError> {
+    let session_service =
+        super::session_service_for(&state, super::requested_model(&headers, &state.config)?);
     // Legacy API: session_id serves as both UUID and content_session_id
-    let summary = state
-        .session_service
+    let summary = session_service
         .summarize_session(&req.session_id, &req.session_id)
         .await
         .map_err(|e| {
@@ -52,6 +55,7 @@ pub async fn session_init_legacy(
         content_session_id,
         req.project,
         req.user_prompt,
+        req.prompt_number,
     )
     .await
     .with_degraded_body(json!({
@@ -77,11 +81,13 @@ pub async fn session_observations_legacy(
 
 pub async fn session_summarize_legacy(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Path(session_db_id): Path<String>,
 ) -> Result<Json<serde_json::Value>, ApiError> {
+    let session_service =
+        super::session_service_for(&state, super::requested_model(&headers, &state.config)?);
     // Legacy API: session_db_id serves as both UUID and content_session_id
-    let summary = state
-        .session_service
+    let summary = session_service
         .summarize_session(&session_db_id, &session_db_id)
         .await
         .map_err(|e| {
@@ -1,5 +1,5 @@
 use crate::api_error::{ApiError, DegradedExt};
-use axum::{Json, extract::State};
+use axum::{Json, extract::State, http::HeaderMap};
 use serde_json::json;
 use std::sync::Arc;
 
@@ -25,6 +25,7 @@ pub async fn api_session_init(
         content_session_id,
         req.project,
         req.user_prompt,
+        req.prompt_number,
     )
     .await
     .with_degraded_body(json!({
@@ -63,8 +64,10 @@ pub async fn api_session_observations(
 
 pub async fn api_session_summarize(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(req): Json<SessionSummarizeRequest>,
 ) -> Result<Json<serde_json::Value>, ApiError> {
+    let model = super::requested_model(&headers, &state.config)?;
     let content_session_id = req
         .content_session_id
         .ok_or(ApiError::BadRequest("Bad Request".into()))?;
@@ -84,8 +87,7 @@ pub async fn api_session_summarize(
     })?;
 
     let cid = content_session_id.clone();
-    let summary = state
-        .session_service
+    let summary = super::session_service_for(&state, model)
         .summarize_session(&session_id, &cid)
         .await
         .map_err(|e| {
@@ -106,3 +108,63 @@ pub async fn api_session_summarize(
         "status": "completed"
     })))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::pg_state;
+
+    async fn init(
+        state: &Arc<AppState>,
+        content_session_id: &str,
+        prompt: &str,
+        prompt_number: Option<u32>,
+    ) {
+        let req = SessionInitRequest {
+            content_session_id: Some(content_session_id.to_owned()),
+            project: Some("prompt-test".to_owned()),
+            user_prompt: Some(prompt.to_owned()),
+            prompt_number,
+        };
+        let Json(resp) = api_session_init(State(Arc::clone(state)), Json(req))
+            .await
+            .unwrap();
+        assert_eq!(resp.status, "active");
+    }
+
+    // Requires a running PostgreSQL instance.
+    // Run with: DATABASE_URL=... cargo test -p opencode-mem-http -- --ignored
+    #[tokio::test]
+    #[ignore]
+    async fn test_every_prompt_of_a_content_session_is_recorded() {
+        let (state, storage) = pg_state().await;
+        let content_id = format!("api-init-{}", uuid::Uuid::new_v4());
+
+        // Each call creates a new session row for the same content session.
+        init(&state, &content_id, "Refactor the queue", None).await;
+        init(&state, &content_id, "continue", None).await;
+        init(&state, &content_id, "continue", Some(2)).await;
+        init(&state, &content_id, "Now add tests", Some(3)).await;
+
+        let prompts: Vec<(i32, String)> = sqlx::query_as(
+            "SELECT prompt_number, prompt_text FROM user_prompts
+             WHERE content_session_id = $1 ORDER BY prompt_number",
+        )
+        .bind(&content_id)
+        .fetch_all(&storage.pool())
+        .await
+        .unwrap();
+        let expected = [
+            (1, "Refactor the queue"),
+            (2, "continue"),
+            (3, "Now add tests"),
+        ];
+        assert_eq!(
+            prompts
+                .iter()
+                .map(|(n, text)| (*n, text.as_str()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
@@ -40,6 +40,8 @@ mod handlers;
 mod query_types;
 mod response_types;
 mod routes;
+#[cfg(test)]
+mod test_support;
 mod viewer;
 
 use std::sync::Arc;
@@ -35,11 +35,13 @@ pub struct SearchQuery {
     pub obs_type: Option<String>,
     pub from: Option<String>,
     pub to: Option<String>,
+    /// One of `ObservationSort::ALL_VARIANTS_STR`; defaults to rank order.
+    pub sort: Option<String>,
 }
 
 impl SearchQuery {
     pub fn capped_limit(&self) -> usize {
-        opencode_mem_core::cap_query_limit(self.limit)
+        opencode_mem_core::cap_result_limit(self.limit)
     }
 }
 
@@ -53,7 +55,7 @@ pub struct TimelineQuery {
 
 impl TimelineQuery {
     pub fn capped_limit(&self) -> usize {
-        opencode_mem_core::cap_query_limit(self.limit)
+        opencode_mem_core::cap_result_limit(self.limit)
     }
 }
 
@@ -65,6 +67,12 @@ pub struct ContextQuery {
     pub session_id: Option<String>,
 }
 
+impl ContextQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BatchRequest {
     pub ids: Vec<String>,
@@ -96,6 +104,10 @@ pub struct SessionInitRequest {
     pub project: Option<String>,
     #[serde(rename = "userPrompt")]
     pub user_prompt: Option<String>,
+    /// Client-side position of `userPrompt`; lets a resubmitted prompt be
+    /// recognized as the same one.
+    #[serde(rename = "promptNumber")]
+    pub prompt_number: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -122,10 +134,48 @@ pub struct PaginationQuery {
 
 impl PaginationQuery {
     pub fn capped_limit(&self) -> usize {
-        opencode_mem_core::cap_query_limit(self.limit)
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectFilesQuery {
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+impl ProjectFilesQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ObservationListQuery {
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+    pub project: Option<String>,
+    /// One of `ObservationSort::ALL_VARIANTS_STR`; defaults to newest first.
+    pub sort: Option<String>,
+}
+
+impl ObservationListQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ObservationCountQuery {
+    pub project: Option<String>,
+    #[serde(rename = "type")]
+    pub obs_type: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct FileSearchQuery {
     #[serde(rename = "filePath")]
@@ -136,7 +186,34 @@ pub struct FileSearchQuery {
 
 impl FileSearchQuery {
     pub fn capped_limit(&self) -> usize {
-        opencode_mem_core::cap_query_limit(self.limit)
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KeywordSearchQuery {
+    pub keyword: String,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+impl KeywordSearchQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConceptSearchQuery {
+    pub concept: String,
+    pub project: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+impl ConceptSearchQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
     }
 }
 
@@ -161,6 +238,12 @@ pub struct ContextPreviewQuery {
     pub format: String,
 }
 
+impl ContextPreviewQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SetProcessingRequest {
     pub active: bool,
@@ -206,6 +289,13 @@ pub struct SearchEntitiesQuery {
     pub limit: i64,
 }
 
+impl SearchEntitiesQuery {
+    pub fn capped_limit(&self) -> i64 {
+        let limit = usize::try_from(self.limit.max(1)).unwrap_or(usize::MAX);
+        i64::try_from(opencode_mem_core::cap_result_limit(limit)).unwrap_or(i64::MAX)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct KnowledgeQuery {
     #[serde(default)]
@@ -215,6 +305,12 @@ pub struct KnowledgeQuery {
     pub knowledge_type: Option<KnowledgeType>,
 }
 
+impl KnowledgeQuery {
+    pub fn capped_limit(&self) -> usize {
+        opencode_mem_core::cap_result_limit(self.limit)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SaveKnowledgeRequest {
     pub knowledge_type: KnowledgeType,
@@ -236,6 +332,26 @@ pub struct SaveMemoryRequest {
     pub noise_level: Option<String>,
 }
 
+/// Fully-formed observation for `POST /api/observations/direct` — stored without LLM compression.
+#[derive(Debug, Deserialize)]
+pub struct DirectObservationRequest {
+    #[serde(alias = "type")]
+    pub observation_type: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub narrative: Option<String>,
+    #[serde(default)]
+    pub facts: Vec<String>,
+    #[serde(default)]
+    pub files_read: Vec<String>,
+    #[serde(default, alias = "files")]
+    pub files_modified: Vec<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    pub noise_level: Option<String>,
+    pub project: Option<String>,
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, reason = "Unwraps are safe in tests")]
 mod tests {
@@ -246,7 +362,7 @@ mod tests {
     fn test_search_query_capped_limit() {
         let q: SearchQuery =
             serde_json::from_value(json!({"q": "x", "limit": 5000})).expect("valid SearchQuery");
-        assert_eq!(q.capped_limit(), opencode_mem_core::MAX_QUERY_LIMIT);
+        assert_eq!(q.capped_limit(), opencode_mem_core::max_result_limit());
     }
 
     #[test]
@@ -260,14 +376,14 @@ mod tests {
     fn test_timeline_query_capped_limit() {
         let q: TimelineQuery =
             serde_json::from_value(json!({"limit": 5000})).expect("valid TimelineQuery");
-        assert_eq!(q.capped_limit(), opencode_mem_core::MAX_QUERY_LIMIT);
+        assert_eq!(q.capped_limit(), opencode_mem_core::max_result_limit());
     }
 
     #[test]
     fn test_pagination_query_capped_limit() {
         let q: PaginationQuery =
             serde_json::from_value(json!({"limit": 5000})).expect("valid PaginationQuery");
-        assert_eq!(q.capped_limit(), opencode_mem_core::MAX_QUERY_LIMIT);
+        assert_eq!(q.capped_limit(), opencode_mem_core::max_result_limit());
     }
 
     #[test]
@@ -291,4 +407,47 @@ mod tests {
         let req = BatchRequest { ids };
         assert!(req.validate().is_ok());
     }
+
+    #[test]
+    fn test_direct_observation_request_accepts_short_aliases() {
+        let req: DirectObservationRequest = serde_json::from_value(json!({
+            "type": "bugfix",
+            "title": "CI: flaky test fixed",
+            "files": ["src/lib.rs"],
+            "keywords": ["ci"]
+        }))
+        .expect("valid DirectObservationRequest");
+        assert_eq!(req.observation_type, "bugfix");
+        assert_eq!(req.files_modified, vec!["src/lib.rs"]);
+        assert!(req.files_read.is_empty());
+        assert!(req.noise_level.is_none());
+    }
+
+    #[test]
+    fn test_oversized_limit_clamped_on_search_and_paginated_paths() {
+        let search: SearchQuery = serde_json::from_value(json!({"q": "x", "limit": 1_000_000}))
+            .expect("valid SearchQuery");
+        let page: PaginationQuery =
+            serde_json::from_value(json!({"limit": 1_000_000})).expect("valid PaginationQuery");
+        let entities: SearchEntitiesQuery = serde_json::from_value(
+            json!({"entity_type": "files", "value": "x", "limit": 1_000_000}),
+        )
+        .expect("valid SearchEntitiesQuery");
+        let knowledge: KnowledgeQuery =
+            serde_json::from_value(json!({"limit": 1_000_000})).expect("valid KnowledgeQuery");
+        let context: ContextQuery =
+            serde_json::from_value(json!({"project": "p", "limit": 1_000_000}))
+                .expect("valid ContextQuery");
+        let preview: ContextPreviewQuery =
+            serde_json::from_value(json!({"project": "p", "limit": 1_000_000}))
+                .expect("valid ContextPreviewQuery");
+        let max = opencode_mem_core::max_result_limit();
+        assert_eq!(max, opencode_mem_core::DEFAULT_MAX_RESULT_LIMIT);
+        assert_eq!(search.capped_limit(), max);
+        assert_eq!(page.capped_limit(), max);
+        assert_eq!(entities.capped_limit(), i64::try_from(max).unwrap());
+        assert_eq!(knowledge.capped_limit(), max);
+        assert_eq!(context.capped_limit(), max);
+        assert_eq!(preview.capped_limit(), max);
+    }
 }
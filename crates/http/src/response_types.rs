@@ -20,6 +20,11 @@ pub struct ObserveBatchResponse {
     pub total: usize,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ObservationCountResponse {
+    pub count: usize,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SessionInitResponse {
     pub session_id: String,
@@ -234,3 +239,36 @@ pub struct HealthResponse {
     pub seconds_until_probe: Option<u64>,
     pub uptime_seconds: u64,
 }
+
+/// Probe result for a single subsystem in `/api/health/detailed`.
+#[derive(Debug, Serialize)]
+pub struct SubsystemHealth {
+    /// `ok`, `degraded`, `loading`, or `disabled`.
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// LLM circuit breaker state in `/api/health/detailed`.
+#[derive(Debug, Serialize)]
+pub struct CircuitBreakerHealth {
+    /// `closed`, `open`, or `half-open`.
+    pub state: &'static str,
+    pub consecutive_failures: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds_until_probe: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DetailedHealthResponse {
+    /// `ok` when no subsystem is degraded.
+    pub status: &'static str,
+    pub storage: SubsystemHealth,
+    pub llm: SubsystemHealth,
+    pub llm_circuit_breaker: CircuitBreakerHealth,
+    pub embeddings: SubsystemHealth,
+    pub infinite_memory: SubsystemHealth,
+    pub uptime_seconds: u64,
+}
@@ -14,6 +14,10 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/", get(viewer::serve_viewer))
         .route("/health", get(health))
         .route("/api/readiness", get(readiness))
+        .route(
+            "/api/health/detailed",
+            get(handlers::health::health_detailed),
+        )
         .route("/api/version", get(version))
         .route("/observe", post(handlers::observations::observe))
         .route(
@@ -24,6 +28,14 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/memory/save",
             post(handlers::observations::save_memory),
         )
+        .route(
+            "/api/observations/direct",
+            post(handlers::observations::save_direct_observation),
+        )
+        .route(
+            "/api/events/commit",
+            post(handlers::observations::record_commit),
+        )
         .route("/search", get(handlers::search::search))
         .route("/hybrid-search", get(handlers::search::hybrid_search))
         .route("/semantic-search", get(handlers::search::semantic_search))
@@ -44,6 +56,10 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/observations",
             get(handlers::observations::get_observations_paginated),
         )
+        .route(
+            "/api/observations/count",
+            get(handlers::observations::count_observations),
+        )
         .route(
             "/api/summaries",
             get(handlers::observations::get_summaries_paginated),
@@ -60,15 +76,27 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/prompt/{id}",
             get(handlers::observations::get_prompt_by_id),
         )
+        .route(
+            "/api/prompt/{id}/observations",
+            get(handlers::observations::get_prompt_observations),
+        )
         .route("/api/search/observations", get(handlers::search::search))
+        .route("/api/search/full", get(handlers::search::search_full))
         .route("/api/search/by-type", get(handlers::search::search))
-        .route("/api/search/by-concept", get(handlers::search::search))
+        .route(
+            "/api/search/by-concept",
+            get(handlers::search::search_by_concept),
+        )
         .route(
             "/api/search/sessions",
             get(handlers::search::search_sessions),
         )
         .route("/api/search/prompts", get(handlers::search::search_prompts))
         .route("/api/search/by-file", get(handlers::search::search_by_file))
+        .route(
+            "/api/search/by-keyword",
+            get(handlers::search::search_by_keyword),
+        )
         .route(
             "/api/context/recent",
             get(handlers::context::get_context_recent),
@@ -76,6 +104,10 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/recent", get(handlers::observations::get_recent))
         .route("/timeline", get(handlers::observations::get_timeline))
         .route("/projects", get(handlers::context::get_projects))
+        .route(
+            "/api/projects/{project}/files",
+            get(handlers::context::get_project_files),
+        )
         .route("/stats", get(handlers::context::get_stats))
         .route(
             "/context/inject",
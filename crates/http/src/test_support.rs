@@ -0,0 +1,113 @@
+//! Shared setup for handler tests.
+//! PostgreSQL-backed ones run with: DATABASE_URL=... cargo test -p opencode-mem-http -- --ignored
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Instant;
+
+use opencode_mem_core::AppConfig;
+use opencode_mem_llm::LlmClient;
+use opencode_mem_service::{
+    KnowledgeService, ObservationService, PendingWriteQueue, QueueService, SearchService,
+    SessionService,
+};
+use opencode_mem_storage::StorageBackend;
+use tokio::sync::{RwLock, Semaphore, broadcast};
+
+use crate::{AppState, Settings};
+
+/// Config with the defaults `AppConfig::from_env` would pick, minus anything
+/// that needs the environment: no database, LLM endpoint or embeddings.
+pub(crate) fn test_config() -> AppConfig {
+    AppConfig {
+        database_url: String::new(),
+        api_key: String::new(),
+        api_url: String::new(),
+        model: "default-model".to_owned(),
+        disable_embeddings: true,
+        embedding_threads: 0,
+        reembed_on_dimension_change: false,
+        infinite_memory_url: None,
+        llm_breaker_threshold: 5,
+        llm_breaker_window_secs: 60,
+        llm_breaker_cooldown_secs: 30,
+        dedup_threshold: 0.85,
+        injection_dedup_threshold: 0.80,
+        queue_workers: 10,
+        max_retry: 3,
+        visibility_timeout_secs: 300,
+        dlq_ttl_days: 7,
+        session_complete_grace_secs: 0,
+        require_narrative: false,
+        strip_ansi: true,
+        prompt_injection_guard: true,
+        summary_language: "English".to_owned(),
+        max_candidates: 0,
+        max_candidate_chars: 0,
+        merge_require_confirm: false,
+        transient_ttl_hours: 0,
+        max_content_chars: 500,
+        max_total_chars: 8000,
+        max_events: 200,
+        infinite_batch_window_ms: 0,
+        infinite_batch_max_events: 100,
+        max_result_limit: 100,
+        admin_token: None,
+        excluded_projects_raw: None,
+        filter_patterns_raw: None,
+        allowed_models: Vec::new(),
+    }
+}
+
+/// Handler state backed by the `DATABASE_URL` database. The LLM is never
+/// reached, and there is no infinite memory or embedding service.
+pub(crate) async fn pg_state() -> (Arc<AppState>, Arc<StorageBackend>) {
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set for tests");
+    let config = Arc::new(AppConfig {
+        database_url,
+        ..test_config()
+    });
+    let storage = Arc::new(
+        StorageBackend::new(&config.database_url)
+            .await
+            .expect("Failed to connect to PG"),
+    );
+    let llm = Arc::new(LlmClient::new(String::new(), String::new(), String::new()).unwrap());
+    let (event_tx, _) = broadcast::channel(100);
+    let pending_writes = Arc::new(PendingWriteQueue::new());
+    let (shutdown_tx, _) = broadcast::channel(1);
+    let state = Arc::new(AppState {
+        semaphore: Arc::new(Semaphore::new(config.queue_workers)),
+        event_tx: event_tx.clone(),
+        processing_active: AtomicBool::new(false),
+        settings: RwLock::new(Settings::default()),
+        infinite_mem: None,
+        observation_service: Arc::new(ObservationService::new(
+            Arc::clone(&storage),
+            Arc::clone(&llm),
+            None,
+            event_tx,
+            None,
+            &config,
+        )),
+        session_service: Arc::new(SessionService::new(Arc::clone(&storage), llm)),
+        knowledge_service: Arc::new(KnowledgeService::new(Arc::clone(&storage), None)),
+        search_service: Arc::new(SearchService::new(
+            Arc::clone(&storage),
+            None,
+            None,
+            config.dedup_threshold,
+        )),
+        queue_service: Arc::new(QueueService::new(
+            Arc::clone(&storage),
+            Arc::clone(&pending_writes),
+            &config,
+        )),
+        pending_writes,
+        background_tasks: Arc::new(tokio::sync::Mutex::new(tokio::task::JoinSet::new())),
+        shutdown_tx,
+        started_at: Instant::now(),
+        config,
+    });
+    (state, storage)
+}
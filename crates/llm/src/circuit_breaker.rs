@@ -0,0 +1,326 @@
+//! Circuit breaker for the LLM endpoint.
+//!
+//! When the endpoint is down, every compression would otherwise retry, time
+//! out and hold a worker permit. After `failure_threshold` consecutive
+//! failures within `failure_window` the circuit opens and calls fail fast with
+//! [`LlmError::CircuitOpen`](crate::LlmError::CircuitOpen) for `cooldown`.
+//! Then one probe call is let through (half-open): success closes the circuit,
+//! failure re-opens it for another cooldown.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Consecutive failures that open the circuit by default.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+
+/// Window in which the consecutive failures must occur by default.
+pub const DEFAULT_FAILURE_WINDOW: Duration = Duration::from_secs(60);
+
+/// How long the circuit stays open before a probe by default.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Closed,
+    Open { since: Instant },
+    HalfOpen { since: Instant },
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: State,
+    failures: u32,
+    first_failure: Option<Instant>,
+}
+
+/// Thread-safe breaker shared by all clones of an `LlmClient`.
+#[derive(Debug)]
+pub struct LlmCircuitBreaker {
+    inner: Mutex<Inner>,
+    failure_threshold: u32,
+    failure_window: Duration,
+    cooldown: Duration,
+}
+
+impl LlmCircuitBreaker {
+    /// Create a closed breaker. A `failure_threshold` of `0` disables it.
+    #[must_use]
+    pub fn new(failure_threshold: u32, failure_window: Duration, cooldown: Duration) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                state: State::Closed,
+                failures: 0,
+                first_failure: None,
+            }),
+            failure_threshold,
+            failure_window,
+            cooldown,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Whether a call may proceed. Transitions Open → HalfOpen once the
+    /// cooldown has elapsed and admits exactly one probe.
+    pub fn should_allow(&self) -> bool {
+        self.should_allow_at(Instant::now())
+    }
+
+    fn should_allow_at(&self, now: Instant) -> bool {
+        if self.failure_threshold == 0 {
+            return true;
+        }
+        let mut inner = self.lock();
+        match inner.state {
+            State::Closed => true,
+            State::Open { since } if now.saturating_duration_since(since) >= self.cooldown => {
+                inner.state = State::HalfOpen { since: now };
+                tracing::info!("LLM circuit breaker: Open → HalfOpen (probing endpoint)");
+                true
+            }
+            // A probe that never reported back (e.g. a cancelled task) must not
+            // wedge the breaker: allow a fresh probe after another cooldown.
+            State::HalfOpen { since } if now.saturating_duration_since(since) >= self.cooldown => {
+                inner.state = State::HalfOpen { since: now };
+                true
+            }
+            State::Open { .. } | State::HalfOpen { .. } => false,
+        }
+    }
+
+    /// Record a call that reached the endpoint and got a usable answer.
+    pub fn record_success(&self) {
+        let mut inner = self.lock();
+        if inner.state != State::Closed {
+            tracing::info!("LLM circuit breaker: → Closed (endpoint recovered)");
+        }
+        inner.state = State::Closed;
+        inner.failures = 0;
+        inner.first_failure = None;
+    }
+
+    /// Record a call that failed because the endpoint was unavailable.
+    pub fn record_failure(&self) {
+        self.record_failure_at(Instant::now());
+    }
+
+    fn record_failure_at(&self, now: Instant) {
+        if self.failure_threshold == 0 {
+            return;
+        }
+        let mut inner = self.lock();
+        match inner.state {
+            State::HalfOpen { .. } => {
+                inner.state = State::Open { since: now };
+                tracing::warn!(
+                    cooldown_secs = self.cooldown.as_secs(),
+                    "LLM circuit breaker: HalfOpen → Open (probe failed)"
+                );
+            }
+            State::Open { .. } => {}
+            State::Closed => {
+                let window_expired = inner
+                    .first_failure
+                    .is_none_or(|first| now.saturating_duration_since(first) > self.failure_window);
+                if window_expired {
+                    inner.first_failure = Some(now);
+                    inner.failures = 0;
+                }
+                inner.failures = inner.failures.saturating_add(1);
+                if inner.failures >= self.failure_threshold {
+                    inner.state = State::Open { since: now };
+                    tracing::warn!(
+                        failures = inner.failures,
+                        cooldown_secs = self.cooldown.as_secs(),
+                        "LLM circuit breaker: Closed → Open"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Current state as a human-readable string (for diagnostics).
+    #[must_use]
+    pub fn state_name(&self) -> &'static str {
+        match self.lock().state {
+            State::Closed => "closed",
+            State::Open { .. } => "open",
+            State::HalfOpen { .. } => "half-open",
+        }
+    }
+
+    /// Whether calls are currently being rejected.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        matches!(self.lock().state, State::Open { .. })
+    }
+
+    /// Consecutive failures counted in the current window.
+    #[must_use]
+    pub fn consecutive_failures(&self) -> u32 {
+        self.lock().failures
+    }
+
+    /// Seconds until the next probe is allowed (0 unless open).
+    #[must_use]
+    pub fn seconds_until_probe(&self) -> u64 {
+        self.seconds_until_probe_at(Instant::now())
+    }
+
+    fn seconds_until_probe_at(&self, now: Instant) -> u64 {
+        match self.lock().state {
+            State::Open { since } => self
+                .cooldown
+                .saturating_sub(now.saturating_duration_since(since))
+                .as_secs(),
+            State::Closed | State::HalfOpen { .. } => 0,
+        }
+    }
+
+    /// Time until a call would be admitted, or `None` if one would be now.
+    /// Unlike [`should_allow`](Self::should_allow) this never admits a probe,
+    /// so pollers can use it to back off while the endpoint is down.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after_at(Instant::now())
+    }
+
+    fn retry_after_at(&self, now: Instant) -> Option<Duration> {
+        if self.failure_threshold == 0 {
+            return None;
+        }
+        match self.lock().state {
+            State::Closed => None,
+            State::Open { since } | State::HalfOpen { since } => {
+                let remaining = self
+                    .cooldown
+                    .saturating_sub(now.saturating_duration_since(since));
+                (!remaining.is_zero()).then_some(remaining)
+            }
+        }
+    }
+}
+
+impl Default for LlmCircuitBreaker {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_FAILURE_THRESHOLD,
+            DEFAULT_FAILURE_WINDOW,
+            DEFAULT_COOLDOWN,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> LlmCircuitBreaker {
+        LlmCircuitBreaker::new(3, Duration::from_secs(60), Duration::from_secs(30))
+    }
+
+    #[test]
+    fn test_opens_after_threshold_within_window() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        cb.record_failure_at(t0);
+        cb.record_failure_at(t0 + Duration::from_secs(1));
+        assert_eq!(cb.state_name(), "closed");
+        cb.record_failure_at(t0 + Duration::from_secs(2));
+        assert_eq!(cb.state_name(), "open");
+        assert!(!cb.should_allow_at(t0 + Duration::from_secs(3)));
+        assert_eq!(cb.seconds_until_probe_at(t0 + Duration::from_secs(12)), 20);
+    }
+
+    #[test]
+    fn test_failures_outside_window_do_not_accumulate() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        cb.record_failure_at(t0);
+        cb.record_failure_at(t0 + Duration::from_secs(1));
+        cb.record_failure_at(t0 + Duration::from_secs(120));
+        assert_eq!(cb.state_name(), "closed");
+        assert_eq!(cb.consecutive_failures(), 1);
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        cb.record_failure_at(t0);
+        cb.record_failure_at(t0);
+        cb.record_success();
+        cb.record_failure_at(t0);
+        assert_eq!(cb.state_name(), "closed");
+    }
+
+    #[test]
+    fn test_half_open_admits_single_probe_then_closes_on_success() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        for _ in 0..3 {
+            cb.record_failure_at(t0);
+        }
+        let after_cooldown = t0 + Duration::from_secs(31);
+        assert!(cb.should_allow_at(after_cooldown));
+        assert_eq!(cb.state_name(), "half-open");
+        assert!(
+            !cb.should_allow_at(after_cooldown),
+            "only one probe at a time"
+        );
+        cb.record_success();
+        assert_eq!(cb.state_name(), "closed");
+        assert!(cb.should_allow_at(after_cooldown));
+    }
+
+    #[test]
+    fn test_half_open_probe_failure_reopens() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        for _ in 0..3 {
+            cb.record_failure_at(t0);
+        }
+        let probe_at = t0 + Duration::from_secs(31);
+        assert!(cb.should_allow_at(probe_at));
+        cb.record_failure_at(probe_at);
+        assert_eq!(cb.state_name(), "open");
+        assert!(!cb.should_allow_at(probe_at + Duration::from_secs(29)));
+        assert!(cb.should_allow_at(probe_at + Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_retry_after_covers_open_and_in_flight_probe() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        assert_eq!(cb.retry_after_at(t0), None);
+        for _ in 0..3 {
+            cb.record_failure_at(t0);
+        }
+        assert_eq!(
+            cb.retry_after_at(t0 + Duration::from_secs(10)),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(cb.retry_after_at(t0 + Duration::from_secs(30)), None);
+
+        let probe_at = t0 + Duration::from_secs(31);
+        assert!(cb.should_allow_at(probe_at));
+        assert_eq!(
+            cb.retry_after_at(probe_at + Duration::from_secs(5)),
+            Some(Duration::from_secs(25)),
+            "wait for the in-flight probe instead of piling on"
+        );
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_breaker() {
+        let cb = LlmCircuitBreaker::new(0, Duration::from_secs(60), Duration::from_secs(30));
+        let t0 = Instant::now();
+        for _ in 0..10 {
+            cb.record_failure_at(t0);
+        }
+        assert!(cb.should_allow_at(t0));
+        assert_eq!(cb.state_name(), "closed");
+    }
+}
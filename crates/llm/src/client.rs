@@ -1,6 +1,7 @@
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use crate::ai_types::{ChatRequest, ChatResponse};
+use crate::circuit_breaker::LlmCircuitBreaker;
 use crate::error::LlmError;
 
 /// Maximum output length for truncation.
@@ -12,6 +13,8 @@ pub struct LlmClient {
     pub(crate) api_key: RwLock<String>,
     pub(crate) base_url: RwLock<String>,
     pub(crate) model: RwLock<String>,
+    pub(crate) summary_language: String,
+    pub(crate) circuit_breaker: Arc<LlmCircuitBreaker>,
 }
 
 impl std::fmt::Debug for LlmClient {
@@ -27,10 +30,29 @@ impl std::fmt::Debug for LlmClient {
             .field("api_key", &"***")
             .field("base_url", &base_url)
             .field("model", &model)
+            .field("summary_language", &self.summary_language)
+            .field("circuit_breaker", &self.circuit_breaker.state_name())
             .finish()
     }
 }
 
+impl Clone for LlmClient {
+    /// Snapshots the current credentials and model; the clone shares the
+    /// underlying HTTP connection pool and circuit breaker but not later
+    /// `update_config` changes.
+    fn clone(&self) -> Self {
+        let read = |lock: &RwLock<String>| lock.read().unwrap_or_else(|e| e.into_inner()).clone();
+        Self {
+            client: self.client.clone(),
+            api_key: RwLock::new(read(&self.api_key)),
+            base_url: RwLock::new(read(&self.base_url)),
+            model: RwLock::new(read(&self.model)),
+            summary_language: self.summary_language.clone(),
+            circuit_breaker: Arc::clone(&self.circuit_breaker),
+        }
+    }
+}
+
 impl LlmClient {
     /// Creates a new LLM client with the given API key, base URL, and model.
     ///
@@ -47,9 +69,25 @@ impl LlmClient {
             api_key: RwLock::new(api_key),
             base_url: RwLock::new(base_url),
             model: RwLock::new(model),
+            summary_language: opencode_mem_core::DEFAULT_SUMMARY_LANGUAGE.to_owned(),
+            circuit_breaker: Arc::new(LlmCircuitBreaker::default()),
         })
     }
 
+    /// Replaces the circuit breaker guarding `chat_completion`.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, breaker: LlmCircuitBreaker) -> Self {
+        self.circuit_breaker = Arc::new(breaker);
+        self
+    }
+
+    /// Sets the language session and infinite-memory summaries are written in.
+    #[must_use]
+    pub fn with_summary_language(mut self, language: String) -> Self {
+        self.summary_language = language;
+        self
+    }
+
     /// Sets a custom model for this client.
     #[must_use]
     pub fn with_model(self, model: String) -> Self {
@@ -112,13 +150,71 @@ impl LlmClient {
         self.model.read().unwrap_or_else(|e| e.into_inner()).clone()
     }
 
+    /// Returns the language summaries are written in.
+    #[must_use]
+    pub fn summary_language(&self) -> &str {
+        &self.summary_language
+    }
+
+    /// Circuit breaker guarding `chat_completion`.
+    #[must_use]
+    pub fn circuit_breaker(&self) -> &LlmCircuitBreaker {
+        &self.circuit_breaker
+    }
+
+    /// Cheap reachability probe: lists models without generating anything.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or the API answers with a non-success status.
+    pub async fn probe(&self) -> Result<(), LlmError> {
+        let response = self
+            .client
+            .get(format!("{}/v1/models", self.base_url()))
+            .header("Authorization", format!("Bearer {}", self.api_key()))
+            .timeout(std::time::Duration::from_secs(5))
+            .send()
+            .await
+            .map_err(LlmError::HttpRequest)?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Could not read error body".to_string());
+        Err(LlmError::HttpStatus {
+            code: status.as_u16(),
+            body,
+        })
+    }
+
     /// Send a chat completion request and return the extracted content string.
     ///
     /// # Errors
-    /// Returns an error if the HTTP request fails, the API returns a
-    /// non-success status, the response body cannot be parsed, or the choices
-    /// array is empty.
+    /// Returns [`LlmError::CircuitOpen`] without calling the API while the
+    /// circuit breaker is open. Otherwise returns an error if the HTTP request
+    /// fails, the API returns a non-success status, the response body cannot
+    /// be parsed, or the choices array is empty.
     pub async fn chat_completion(&self, request: &ChatRequest) -> Result<String, LlmError> {
+        if !self.circuit_breaker.should_allow() {
+            return Err(LlmError::CircuitOpen {
+                retry_after_secs: self.circuit_breaker.seconds_until_probe(),
+            });
+        }
+        let result = self.chat_completion_with_retries(request).await;
+        match &result {
+            Err(e) if e.is_unavailable() => self.circuit_breaker.record_failure(),
+            // Any answer from the endpoint, even a rejected one, proves it is up.
+            _ => self.circuit_breaker.record_success(),
+        }
+        result
+    }
+
+    async fn chat_completion_with_retries(
+        &self,
+        request: &ChatRequest,
+    ) -> Result<String, LlmError> {
         const MAX_RETRIES: usize = 3;
         const RETRY_DELAYS: [u64; 4] = [0, 1, 2, 4];
         let mut last_error: Option<LlmError> = None;
@@ -23,6 +23,8 @@ pub enum LlmError {
     ClientInit(String),
     #[error("all retries exhausted, last error: {0}")]
     RetriesExhausted(Box<LlmError>),
+    #[error("circuit open: LLM endpoint unavailable, next probe in {retry_after_secs}s")]
+    CircuitOpen { retry_after_secs: u64 },
 }
 
 impl LlmError {
@@ -33,7 +35,18 @@ impl LlmError {
             Self::HttpRequest(_) => true,
             Self::HttpStatus { code, .. } => matches!(code, 429 | 500 | 502 | 503 | 529),
             Self::RetriesExhausted(_) => false,
+            Self::CircuitOpen { .. } => true,
             _ => false,
         }
     }
+
+    /// Whether this error means the endpoint could not be reached or kept
+    /// failing, and should count against the circuit breaker.
+    #[must_use]
+    pub fn is_unavailable(&self) -> bool {
+        match self {
+            Self::RetriesExhausted(_) => true,
+            other => other.is_transient() && !matches!(other, Self::CircuitOpen { .. }),
+        }
+    }
 }
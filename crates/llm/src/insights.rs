@@ -196,7 +196,7 @@ impl LlmClient {
         };
 
         let content = self.chat_completion(&request).await?;
-        let clean_json = opencode_mem_core::strip_markdown_json(&content);
+        let clean_json = opencode_mem_core::extract_json(&content);
         serde_json::from_str(clean_json).map_err(|e| LlmError::JsonParse {
             context: format!(
                 "insights response (content: {})",
@@ -70,7 +70,7 @@ Return JSON: {{"extract": false, "reason": "..."}}"#,
         };
 
         let content = self.chat_completion(&request).await?;
-        let stripped = opencode_mem_core::strip_markdown_json(&content);
+        let stripped = opencode_mem_core::extract_json(&content);
         let extraction: KnowledgeExtractionResult =
             serde_json::from_str(stripped).map_err(|e| LlmError::JsonParse {
                 context: format!(
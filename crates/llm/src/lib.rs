@@ -21,6 +21,7 @@
 )]
 
 mod ai_types;
+mod circuit_breaker;
 mod client;
 mod compression_prompt;
 pub mod error;
@@ -30,7 +31,9 @@ mod observation;
 mod summary;
 
 pub use ai_types::{ChatRequest, Message, ResponseFormat, ResponseFormatType};
+pub use circuit_breaker::LlmCircuitBreaker;
 pub use client::LlmClient;
+pub use compression_prompt::init_prompt_guard_config;
 pub use error::LlmError;
 pub use observation::CompressionResult;
 
@@ -32,7 +32,7 @@ fn parse_observation_response(
     project: Option<&str>,
     candidates: &[opencode_mem_core::Observation],
 ) -> Result<CompressionResult, LlmError> {
-    let stripped = opencode_mem_core::strip_markdown_json(response);
+    let stripped = opencode_mem_core::extract_json(response);
     let obs_json: ObservationJson =
         serde_json::from_str(stripped).map_err(|e| LlmError::JsonParse {
             context: format!(
@@ -240,7 +240,7 @@ impl LlmClient {
         };
 
         let response = self.chat_completion(&request).await?;
-        let stripped = opencode_mem_core::strip_markdown_json(&response);
+        let stripped = opencode_mem_core::extract_json(&response);
         let meta: MetadataJson =
             serde_json::from_str(stripped).map_err(|e| LlmError::JsonParse {
                 context: format!(
@@ -30,15 +30,7 @@ impl LlmClient {
             .collect::<Vec<_>>()
             .join("\n");
 
-        let prompt = format!(
-            r#"Summarize this coding session based on the observations below.
-Write 2-3 sentences highlighting key accomplishments and decisions.
-
-Observations:
-{obs_text}
-
-Return JSON: {{"summary": "..."}}"#
-        );
+        let prompt = build_session_summary_prompt(&obs_text, self.summary_language());
 
         let request = ChatRequest {
             model: self.model(),
@@ -53,7 +45,7 @@ Return JSON: {{"summary": "..."}}"#
         };
 
         let content = self.chat_completion(&request).await?;
-        let stripped = opencode_mem_core::strip_markdown_json(&content);
+        let stripped = opencode_mem_core::extract_json(&content);
         let summary: SummaryJson =
             serde_json::from_str(stripped).map_err(|e| LlmError::JsonParse {
                 context: format!(
@@ -65,3 +57,19 @@ Return JSON: {{"summary": "..."}}"#
         Ok(summary.summary)
     }
 }
+
+fn build_session_summary_prompt(obs_text: &str, language: &str) -> String {
+    format!(
+        r#"Summarize this coding session based on the observations below.
+Write 2-3 sentences in {language} highlighting key accomplishments and decisions.
+
+Observations:
+{obs_text}
+
+Return JSON: {{"summary": "..."}}"#
+    )
+}
+
+#[cfg(test)]
+#[path = "summary_tests.rs"]
+mod tests;
@@ -0,0 +1,20 @@
+use super::*;
+
+#[test]
+fn session_summary_prompt_names_configured_language() {
+    let prompt = build_session_summary_prompt("- [bugfix] Fixed login: auth", "German");
+    assert!(prompt.contains("2-3 sentences in German"));
+    assert!(prompt.contains("Fixed login"));
+}
+
+#[test]
+fn client_defaults_to_shared_summary_language() {
+    let client =
+        LlmClient::new(String::new(), String::new(), String::new()).expect("client should build");
+    assert_eq!(
+        client.summary_language(),
+        opencode_mem_core::DEFAULT_SUMMARY_LANGUAGE
+    );
+    let client = client.with_summary_language("English".to_owned());
+    assert_eq!(client.clone().summary_language(), "English");
+}
@@ -1,4 +1,5 @@
 mod context_aware_tests;
 mod noise_level_tests;
+mod prompt_guard_tests;
 mod test_helpers;
 mod utils_tests;
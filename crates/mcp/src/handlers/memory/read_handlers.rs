@@ -1,4 +1,4 @@
-use opencode_mem_core::MAX_BATCH_IDS;
+use opencode_mem_core::{MAX_BATCH_IDS, ProjectId};
 use opencode_mem_service::SearchService;
 
 use crate::handlers::{cb_fast_fail_read, degrade_read_err, mcp_err, mcp_ok};
@@ -15,9 +15,13 @@ pub(in crate::handlers) async fn handle_search(
     let query = args
         .get("query")
         .and_then(|q| q.as_str())
-        .filter(|s| !s.is_empty());
+        .and_then(opencode_mem_core::normalize_query);
 
-    let project = args.get("project").and_then(|p| p.as_str());
+    let project = args
+        .get("project")
+        .and_then(|p| p.as_str())
+        .map(ProjectId::new)
+        .filter(|p| !p.as_str().is_empty());
     let obs_type = args.get("type").and_then(|t| t.as_str());
     let from = args
         .get("from")
@@ -29,7 +33,14 @@ pub(in crate::handlers) async fn handle_search(
         .filter(|s| !s.is_empty());
 
     match search_service
-        .smart_search(query, project, obs_type, from, to, limit)
+        .smart_search(
+            query,
+            project.as_ref().map(ProjectId::as_str),
+            obs_type,
+            from,
+            to,
+            limit,
+        )
         .await
     {
         Ok(results) => {
@@ -136,14 +147,22 @@ pub(in crate::handlers) async fn handle_memory_get(
 
 pub(in crate::handlers) async fn handle_memory_recent(
     search_service: &SearchService,
-    _args: &serde_json::Value,
+    args: &serde_json::Value,
     limit: usize,
 ) -> serde_json::Value {
     let cb = search_service.circuit_breaker();
     if let Some(degraded) = cb_fast_fail_read::<Vec<opencode_mem_core::Observation>>(cb) {
         return degraded;
     }
-    match search_service.get_recent_observations(limit).await {
+    let project = args
+        .get("project")
+        .and_then(|p| p.as_str())
+        .map(ProjectId::new)
+        .filter(|p| !p.as_str().is_empty());
+    match search_service
+        .get_recent_observations(project.as_ref().map(ProjectId::as_str), limit)
+        .await
+    {
         Ok(results) => {
             cb.record_success();
             mcp_ok(&results)
@@ -160,7 +179,7 @@ pub(in crate::handlers) async fn handle_hybrid_search(
     let Some(query) = args
         .get("query")
         .and_then(|q| q.as_str())
-        .filter(|s| !s.is_empty())
+        .and_then(opencode_mem_core::normalize_query)
     else {
         return mcp_err("'query' parameter is required and must not be empty");
     };
@@ -185,7 +204,7 @@ pub(in crate::handlers) async fn handle_semantic_search(
     let Some(query) = args
         .get("query")
         .and_then(|q| q.as_str())
-        .filter(|s| !s.is_empty())
+        .and_then(opencode_mem_core::normalize_query)
     else {
         return mcp_err("'query' parameter is required and must not be empty");
     };
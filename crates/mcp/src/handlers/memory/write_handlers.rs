@@ -20,7 +20,10 @@ pub(in crate::handlers) async fn handle_save_memory(
     }
 
     let title = args.get("title").and_then(|t| t.as_str());
-    let project = args.get("project").and_then(|p| p.as_str());
+    let project = args
+        .get("project")
+        .and_then(|p| p.as_str())
+        .filter(|s| !s.is_empty());
     let observation_type = match args.get("observation_type") {
         Some(serde_json::Value::Null) | None => None,
         Some(serde_json::Value::String(raw)) => {
@@ -25,19 +25,36 @@ fn setup_observation_service(backend: StorageBackend) -> opencode_mem_service::O
         model: String::new(),
         disable_embeddings: true,
         embedding_threads: 0,
+        reembed_on_dimension_change: false,
         infinite_memory_url: None,
+        llm_breaker_threshold: 5,
+        llm_breaker_window_secs: 60,
+        llm_breaker_cooldown_secs: 30,
         dedup_threshold: 0.85,
         injection_dedup_threshold: 0.80,
         queue_workers: 10,
         max_retry: 3,
         visibility_timeout_secs: 300,
         dlq_ttl_days: 7,
+        session_complete_grace_secs: 0,
+        require_narrative: false,
+        strip_ansi: true,
+        prompt_injection_guard: true,
+        summary_language: "English".to_owned(),
+        max_candidates: 0,
+        max_candidate_chars: 0,
+        merge_require_confirm: false,
+        transient_ttl_hours: 0,
         max_content_chars: 500,
         max_total_chars: 8000,
         max_events: 200,
+        infinite_batch_window_ms: 0,
+        infinite_batch_max_events: 100,
+        max_result_limit: 100,
         admin_token: None,
         excluded_projects_raw: None,
         filter_patterns_raw: None,
+        allowed_models: Vec::new(),
     };
     opencode_mem_service::ObservationService::new(
         Arc::new(backend),
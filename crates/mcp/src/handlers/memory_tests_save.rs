@@ -92,6 +92,24 @@ async fn test_save_memory_with_project() {
     assert_eq!(obs.project.as_deref(), Some("test-project"));
 }
 
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn test_save_memory_empty_project_is_unscoped() {
+    let backend = setup_storage().await;
+    let obs_service = setup_observation_service(backend);
+    let pending_writes = PendingWriteQueue::new();
+    let args = json!({
+        "text": "unscoped narrative",
+        "project": ""
+    });
+    let result = handle_save_memory(&obs_service, &pending_writes, &args).await;
+
+    assert!(result.get("isError").is_none());
+    let obs_json = result["content"][0]["text"].as_str().unwrap();
+    let obs: Observation = serde_json::from_str(obs_json).unwrap();
+    assert!(obs.project.is_none());
+}
+
 #[tokio::test]
 #[ignore = "requires DATABASE_URL"]
 async fn test_save_memory_success_returns_observation() {
@@ -146,3 +146,87 @@ async fn test_search_with_date_filters() {
     let results: Vec<serde_json::Value> = serde_json::from_str(content_text).unwrap();
     assert!(results.is_empty());
 }
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+#[expect(clippy::unwrap_used, reason = "test code")]
+async fn test_memory_recent_project_scope() {
+    let backend = setup_storage().await;
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    // Stored projects are normalized; callers pass the name as they know it.
+    let project_a = format!("scope_a_{suffix}");
+    let project_b = format!("scope_b_{suffix}");
+    for project in [&project_a, &project_b] {
+        let obs = Observation::builder(
+            format!("obs-{project}"),
+            "session-scope".to_owned(),
+            ObservationType::Discovery,
+            format!("recent scope test {project}"),
+        )
+        .project(project.clone())
+        .build();
+        assert!(backend.save_observation(&obs).await.unwrap());
+    }
+    let search_svc = setup_search_service(backend);
+
+    let requested = format!("Scope-A-{suffix}");
+    let result = handle_memory_recent(&search_svc, &json!({"project": requested}), 100).await;
+    assert!(result.get("isError").is_none());
+    let observations: Vec<Observation> =
+        serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert!(
+        observations
+            .iter()
+            .any(|o| o.id.as_ref() == format!("obs-{project_a}"))
+    );
+    assert!(
+        observations
+            .iter()
+            .all(|o| o.project.is_none() || o.project.as_deref() == Some(project_a.as_str())),
+        "scoped recent must not return other projects"
+    );
+
+    let result = handle_memory_recent(&search_svc, &json!({}), 100).await;
+    let observations: Vec<Observation> =
+        serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert!(
+        observations
+            .iter()
+            .any(|o| o.id.as_ref() == format!("obs-{project_b}"))
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+#[expect(clippy::unwrap_used, reason = "test code")]
+async fn test_search_project_scope() {
+    let backend = setup_storage().await;
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let project_a = format!("search_a_{suffix}");
+    let project_b = format!("search_b_{suffix}");
+    for project in [&project_a, &project_b] {
+        let obs = Observation::builder(
+            format!("obs-{project}"),
+            "session-scope".to_owned(),
+            ObservationType::Discovery,
+            format!("project scoped search {project}"),
+        )
+        .project(project.clone())
+        .build();
+        assert!(backend.save_observation(&obs).await.unwrap());
+    }
+    let search_svc = setup_search_service(backend);
+
+    let result = handle_search(
+        &search_svc,
+        &json!({"query": "project scoped search", "project": format!("Search-A-{suffix}")}),
+        50,
+    )
+    .await;
+    assert!(result.get("isError").is_none());
+    let results: Vec<serde_json::Value> =
+        serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap();
+    let ids: Vec<&str> = results.iter().filter_map(|r| r["id"].as_str()).collect();
+    assert!(ids.contains(&format!("obs-{project_a}").as_str()));
+    assert!(!ids.contains(&format!("obs-{project_b}").as_str()));
+}
@@ -68,6 +68,28 @@ fn test_mcp_tool_parse_invalid() {
     assert_eq!(McpTool::parse("search "), None);
 }
 
+#[test]
+fn test_project_scoped_tools_accept_project() {
+    let tools_json = get_tools_json();
+    let tools = tools_json["tools"].as_array().expect("tools array");
+    for name in ["search", "memory_recent", "save_memory"] {
+        assert!(McpTool::parse(name).is_some(), "{name} should parse");
+        let tool = tools
+            .iter()
+            .find(|t| t["name"] == name)
+            .unwrap_or_else(|| panic!("{name} schema missing"));
+        assert_eq!(
+            tool["inputSchema"]["properties"]["project"]["type"], "string",
+            "{name} should accept an optional project"
+        );
+        let required = tool["inputSchema"]["required"].as_array();
+        assert!(
+            required.is_none_or(|r| !r.iter().any(|v| v == "project")),
+            "project must stay optional for {name}"
+        );
+    }
+}
+
 #[test]
 #[expect(clippy::indexing_slicing, reason = "test code with known structure")]
 fn test_mcp_ok_serialization() {
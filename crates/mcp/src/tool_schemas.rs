@@ -19,7 +19,7 @@ pub fn get_tools_json() -> serde_json::Value {
                     "properties": {
                         "query": { "type": "string", "description": "Search query" },
                         "limit": { "type": "integer", "default": opencode_mem_core::DEFAULT_QUERY_LIMIT },
-                        "project": { "type": "string", "description": "Filter by project" },
+                        "project": { "type": "string", "description": "Only search this project (omit for all projects)" },
                         "type": { "type": "string", "description": format!("Filter by observation type ({})", opencode_mem_core::ObservationType::ALL_VARIANTS_STR) },
                         "from": { "type": "string", "description": "Start date (ISO 8601)" },
                         "to": { "type": "string", "description": "End date (ISO 8601)" }
@@ -67,11 +67,12 @@ pub fn get_tools_json() -> serde_json::Value {
             },
             {
                 "name": "memory_recent",
-                "description": "Get recent observations",
+                "description": "Get recent observations. Params: limit, project",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "limit": { "type": "integer", "default": 10 }
+                        "limit": { "type": "integer", "default": 10 },
+                        "project": { "type": "string", "description": "Only return observations from this project (omit for all projects)" }
                     }
                 }
             },
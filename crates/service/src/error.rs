@@ -55,6 +55,15 @@ impl ServiceError {
         }
     }
 
+    /// Remaining cooldown when this error is a fast-fail from an open LLM
+    /// circuit breaker. Such failures say nothing about the request itself.
+    pub fn llm_circuit_retry_after(&self) -> Option<u64> {
+        match self {
+            Self::Llm(LlmError::CircuitOpen { retry_after_secs }) => Some(*retry_after_secs),
+            _ => None,
+        }
+    }
+
     /// Whether this error represents a not-found condition.
     pub fn is_not_found(&self) -> bool {
         matches!(self, Self::Storage(StorageError::NotFound { .. }))
@@ -0,0 +1,88 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use opencode_mem_core::user_event;
+use opencode_mem_llm::LlmClient;
+
+use super::*;
+use crate::test_support::pg_setup;
+
+async fn batching_service(max_events: usize) -> InfiniteMemoryService {
+    let (storage, _) = pg_setup().await;
+    let llm = Arc::new(LlmClient::new(String::new(), String::new(), String::new()).unwrap());
+    InfiniteMemoryService::new(storage.pool(), llm)
+        .await
+        .unwrap()
+        .with_event_batching(Duration::from_secs(60), max_events)
+}
+
+/// Returns (rows, distinct insert statements) for the session. Rows written by
+/// one statement share `ts`, since it defaults to the transaction's `NOW()`.
+async fn stored(svc: &InfiniteMemoryService, session_id: &str) -> (i64, i64) {
+    sqlx::query_as("SELECT COUNT(*), COUNT(DISTINCT ts) FROM raw_events WHERE session_id = $1")
+        .bind(session_id)
+        .fetch_one(&svc.pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_rapid_events_are_written_in_one_insert() {
+    let svc = batching_service(100).await;
+    let session_id = format!("batch-test-{}", uuid::Uuid::new_v4());
+
+    for i in 0..5 {
+        let event = user_event(&session_id, None, &format!("message {i}"));
+        svc.record_event(event).await.unwrap();
+    }
+    assert_eq!(stored(&svc, &session_id).await, (0, 0), "still buffered");
+
+    svc.flush_events().await;
+    assert_eq!(stored(&svc, &session_id).await, (5, 1));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_failed_flush_is_rebuffered_not_lost() {
+    let svc = batching_service(3).await;
+    let session_id = format!("batch-test-{}", uuid::Uuid::new_v4());
+    for _ in 0..3 {
+        svc.circuit_breaker().record_failure();
+    }
+
+    for i in 0..3 {
+        let event = user_event(&session_id, None, &format!("message {i}"));
+        svc.record_event(event).await.unwrap();
+    }
+    assert_eq!(stored(&svc, &session_id).await.0, 0);
+
+    svc.circuit_breaker().record_success();
+    svc.flush_events().await;
+    assert_eq!(stored(&svc, &session_id).await, (3, 1));
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_rejected_event_is_dropped_without_blocking_its_batch() {
+    let svc = batching_service(3).await;
+    let session_id = format!("batch-test-{}", uuid::Uuid::new_v4());
+
+    // PostgreSQL rejects `\u0000` in jsonb, so the batch insert fails for good.
+    for text in ["before", "bad \u{0} byte", "after"] {
+        svc.record_event(user_event(&session_id, None, text))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(stored(&svc, &session_id).await.0, 2);
+    svc.flush_events().await;
+    assert_eq!(
+        stored(&svc, &session_id).await.0,
+        2,
+        "nothing left to retry"
+    );
+}
@@ -0,0 +1,154 @@
+//! Write buffer that turns bursts of infinite-memory events into one multi-row insert.
+
+use std::time::Duration;
+
+use opencode_mem_core::RawInfiniteEvent;
+use tokio::sync::Mutex;
+
+/// Upper bound on events per flush; keeps a batch well below PostgreSQL's
+/// 65535 bind-parameter limit (7 parameters per event).
+pub(crate) const MAX_BATCH_EVENTS: usize = 1000;
+
+/// Upper bound on events held for retry while the database is unreachable;
+/// beyond this the oldest events are dropped rather than growing without limit.
+pub(crate) const MAX_PENDING_EVENTS: usize = 10 * MAX_BATCH_EVENTS;
+
+/// What the caller must do after [`EventBuffer::push`].
+#[derive(Debug)]
+pub(crate) enum PushOutcome {
+    /// First event of a new window — schedule a flush after the window elapses.
+    WindowOpened,
+    /// Added to an already-scheduled batch.
+    Buffered,
+    /// Size threshold reached — write this batch now.
+    Full(Vec<RawInfiniteEvent>),
+}
+
+pub(crate) struct EventBuffer {
+    pending: Mutex<Vec<RawInfiniteEvent>>,
+    pub(crate) window: Duration,
+    pub(crate) max_events: usize,
+}
+
+/// Result of putting a failed batch back with [`EventBuffer::requeue`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Requeued {
+    /// The buffer was empty, so no flush is scheduled yet — the caller must schedule one.
+    pub(crate) needs_flush: bool,
+    /// Oldest events discarded to stay within [`MAX_PENDING_EVENTS`].
+    pub(crate) dropped: usize,
+}
+
+impl EventBuffer {
+    pub(crate) fn new(window: Duration, max_events: usize) -> Self {
+        Self {
+            pending: Mutex::new(Vec::new()),
+            window,
+            max_events: max_events.clamp(1, MAX_BATCH_EVENTS),
+        }
+    }
+
+    pub(crate) async fn push(&self, event: RawInfiniteEvent) -> PushOutcome {
+        let mut pending = self.pending.lock().await;
+        pending.push(event);
+        if pending.len() >= self.max_events {
+            PushOutcome::Full(std::mem::take(&mut *pending))
+        } else if pending.len() == 1 {
+            PushOutcome::WindowOpened
+        } else {
+            PushOutcome::Buffered
+        }
+    }
+
+    /// Take everything buffered so far.
+    pub(crate) async fn drain(&self) -> Vec<RawInfiniteEvent> {
+        std::mem::take(&mut *self.pending.lock().await)
+    }
+
+    /// Put events that failed to write back in front of anything buffered since,
+    /// so they are retried by the next flush in their original order.
+    pub(crate) async fn requeue(&self, mut batch: Vec<RawInfiniteEvent>) -> Requeued {
+        let mut pending = self.pending.lock().await;
+        let needs_flush = pending.is_empty();
+        batch.append(&mut pending);
+        let dropped = batch.len().saturating_sub(MAX_PENDING_EVENTS);
+        batch.drain(..dropped);
+        *pending = batch;
+        Requeued {
+            needs_flush,
+            dropped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(i: usize) -> RawInfiniteEvent {
+        opencode_mem_core::user_event("session", None, &format!("message {i}"))
+    }
+
+    #[tokio::test]
+    async fn test_rapid_events_within_window_form_one_batch() {
+        let buffer = EventBuffer::new(Duration::from_millis(500), 100);
+        assert!(matches!(
+            buffer.push(event(0)).await,
+            PushOutcome::WindowOpened
+        ));
+        for i in 1..10 {
+            assert!(matches!(buffer.push(event(i)).await, PushOutcome::Buffered));
+        }
+        assert_eq!(buffer.drain().await.len(), 10);
+        assert!(buffer.drain().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_size_threshold_flushes_immediately() {
+        let buffer = EventBuffer::new(Duration::from_secs(60), 3);
+        buffer.push(event(0)).await;
+        buffer.push(event(1)).await;
+        let PushOutcome::Full(batch) = buffer.push(event(2)).await else {
+            panic!("third event should fill the batch");
+        };
+        assert_eq!(batch.len(), 3);
+        assert!(matches!(
+            buffer.push(event(3)).await,
+            PushOutcome::WindowOpened
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_requeued_batch_is_retried_before_newer_events() {
+        let buffer = EventBuffer::new(Duration::from_secs(60), 100);
+        let failed = vec![event(0), event(1)];
+        assert_eq!(
+            buffer.requeue(failed).await,
+            Requeued {
+                needs_flush: true,
+                dropped: 0
+            }
+        );
+        assert!(matches!(buffer.push(event(2)).await, PushOutcome::Buffered));
+
+        let retried = buffer.drain().await;
+        let order: Vec<_> = retried.iter().map(|e| e.content.clone()).collect();
+        let expected: Vec<_> = (0..3).map(|i| event(i).content).collect();
+        assert_eq!(order, expected);
+    }
+
+    #[tokio::test]
+    async fn test_requeue_drops_oldest_beyond_bound() {
+        let buffer = EventBuffer::new(Duration::from_secs(60), 100);
+        buffer.push(event(0)).await;
+        let failed = (1..=MAX_PENDING_EVENTS).map(event).collect();
+        let requeued = buffer.requeue(failed).await;
+        assert!(!requeued.needs_flush, "a flush is already scheduled");
+        assert_eq!(requeued.dropped, 1);
+
+        let retried = buffer.drain().await;
+        assert_eq!(retried.len(), MAX_PENDING_EVENTS);
+        assert_eq!(retried[0].content, event(2).content);
+        assert_eq!(retried.last().unwrap().content, event(0).content);
+    }
+}
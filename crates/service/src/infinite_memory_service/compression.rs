@@ -1,7 +1,5 @@
 use anyhow::Result;
-use opencode_mem_core::{
-    InfiniteSummary, StoredInfiniteEvent, SummaryEntities, strip_markdown_json,
-};
+use opencode_mem_core::{InfiniteSummary, StoredInfiniteEvent, SummaryEntities, extract_json};
 use opencode_mem_llm::LlmClient;
 use std::sync::OnceLock;
 
@@ -90,22 +88,23 @@ pub async fn compress_events(
     }
 
     let prompt = format!(
-        r#"Проанализируй эти {} событий и верни JSON:
+        r#"Analyze these {} events and return JSON:
 {{
-  "summary": "Краткое описание на русском (2-3 предложения)",
+  "summary": "Brief description in {language} (2-3 sentences)",
   "entities": {{
-    "files": ["список изменённых файлов"],
-    "functions": ["упомянутые функции"],
-    "libraries": ["внешние библиотеки"],
-    "errors": ["типы ошибок"],
-    "decisions": ["ключевые решения"]
+    "files": ["modified files"],
+    "functions": ["functions mentioned"],
+    "libraries": ["external libraries"],
+    "errors": ["error types"],
+    "decisions": ["key decisions"]
   }}
 }}
 
-События:
+Events:
 {}"#,
         events.len(),
-        events_text.join("\n")
+        events_text.join("\n"),
+        language = llm.summary_language(),
     );
 
     let request = opencode_mem_llm::ChatRequest {
@@ -125,7 +124,7 @@ pub async fn compress_events(
         .await
         .map_err(|e| anyhow::anyhow!("Failed to send compression request: {}", e))?;
 
-    let content = strip_markdown_json(&content);
+    let content = extract_json(&content);
     let parsed: serde_json::Value = serde_json::from_str(content).map_err(|e| {
         anyhow::anyhow!(
             "Failed to parse AI JSON response: {}. Content: {}",
@@ -165,10 +164,11 @@ pub async fn compress_summaries(llm: &LlmClient, summaries: &[InfiniteSummary])
         .collect();
 
     let prompt = format!(
-        "Объедини эти {} сводок в одну краткую сводку на русском (2-3 предложения). \
-         Сохрани ключевые факты, файлы, решения.\n\n{}",
+        "Merge these {} summaries into one brief summary in {language} (2-3 sentences). \
+         Keep key facts, files and decisions.\n\n{}",
         summaries.len(),
-        summaries_text.join("\n\n")
+        summaries_text.join("\n\n"),
+        language = llm.summary_language(),
     );
 
     let request = opencode_mem_llm::ChatRequest {
@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod batching_tests;
+mod buffer;
 mod compression;
 mod pipeline;
 mod queries;
@@ -10,6 +13,9 @@ use anyhow::Result;
 use sqlx::PgPool;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use buffer::{EventBuffer, PushOutcome};
 
 pub use compression::init_compression_config;
 
@@ -19,6 +25,8 @@ pub struct InfiniteMemoryService {
     llm: Arc<LlmClient>,
     circuit_breaker: Arc<CircuitBreaker>,
     migrations_pending: Arc<AtomicBool>,
+    /// Write buffer for raw events; `None` writes every event immediately.
+    buffer: Option<Arc<EventBuffer>>,
 }
 
 impl InfiniteMemoryService {
@@ -46,6 +54,7 @@ impl InfiniteMemoryService {
             llm,
             circuit_breaker: Arc::new(CircuitBreaker::new()),
             migrations_pending: Arc::new(AtomicBool::new(migrations_pending)),
+            buffer: None,
         };
 
         // Spawn a background loop to retry deferred migrations periodically.
@@ -96,9 +105,18 @@ impl InfiniteMemoryService {
             llm,
             circuit_breaker: Arc::new(cb),
             migrations_pending: Arc::new(AtomicBool::new(true)),
+            buffer: None,
         }
     }
 
+    /// Buffer raw events for up to `window` (or until `max_events` are pending)
+    /// and write them as one multi-row insert. A zero window keeps per-event writes.
+    #[must_use]
+    pub fn with_event_batching(mut self, window: Duration, max_events: usize) -> Self {
+        self.buffer = (!window.is_zero()).then(|| Arc::new(EventBuffer::new(window, max_events)));
+        self
+    }
+
     #[must_use]
     pub fn circuit_breaker(&self) -> &CircuitBreaker {
         &self.circuit_breaker
@@ -132,6 +150,12 @@ impl InfiniteMemoryService {
         self.migrations_pending.load(Ordering::Acquire)
     }
 
+    /// Liveness probe for health checks.
+    pub async fn ping(&self) -> Result<(), StorageError> {
+        self.guarded(|| opencode_mem_storage::pg_storage::ping_pool(&self.pool))
+            .await
+    }
+
     pub async fn store_event(&self, event: RawInfiniteEvent) -> Result<i64, StorageError> {
         self.guarded(|| {
             opencode_mem_storage::pg_storage::infinite_memory::store_infinite_event(
@@ -142,6 +166,172 @@ impl InfiniteMemoryService {
         .await
     }
 
+    /// Record an event through the write buffer when batching is enabled,
+    /// otherwise store it immediately.
+    ///
+    /// With batching the event is accepted into the buffer and `Ok` is returned
+    /// before it reaches the database. A batch that fails to write while the
+    /// database is unreachable is put back and retried, so an error here only
+    /// ever comes from an unbatched write.
+    pub async fn record_event(&self, event: RawInfiniteEvent) -> Result<(), StorageError> {
+        let Some(buffer) = &self.buffer else {
+            return self.store_event(event).await.map(|_| ());
+        };
+        match buffer.push(event).await {
+            PushOutcome::Full(batch) => {
+                if let Err((e, unwritten)) = self.write_batch(batch).await {
+                    self.requeue_events(buffer, unwritten, &e).await;
+                }
+            }
+            PushOutcome::WindowOpened => self.schedule_flush(buffer.window),
+            PushOutcome::Buffered => {}
+        }
+        Ok(())
+    }
+
+    /// Write out everything currently buffered. Called on shutdown; events
+    /// that still cannot be written are logged as lost.
+    pub async fn flush_events(&self) {
+        let Some(buffer) = &self.buffer else {
+            return;
+        };
+        if let Err((e, unwritten)) = self.write_batch(buffer.drain().await).await {
+            tracing::error!(
+                error = %e,
+                count = unwritten.len(),
+                "Failed to flush buffered infinite memory events on shutdown — they are lost"
+            );
+        }
+    }
+
+    fn schedule_flush(&self, delay: Duration) {
+        let this = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let Some(buffer) = &this.buffer else {
+                return;
+            };
+            if let Err((e, unwritten)) = this.write_batch(buffer.drain().await).await {
+                this.requeue_events(buffer, unwritten, &e).await;
+            }
+        });
+    }
+
+    /// Put a failed batch back into the buffer and make sure a retry is scheduled.
+    /// While the breaker is open the retry waits for its next probe.
+    async fn requeue_events(
+        &self,
+        buffer: &EventBuffer,
+        unwritten: Vec<RawInfiniteEvent>,
+        error: &StorageError,
+    ) {
+        let count = unwritten.len();
+        let requeued = buffer.requeue(unwritten).await;
+        let delay = match error {
+            StorageError::Unavailable {
+                seconds_until_probe,
+            } => buffer.window.max(Duration::from_secs(*seconds_until_probe)),
+            _ => buffer.window,
+        };
+        tracing::warn!(
+            error = %error,
+            count,
+            retry_in_ms = delay.as_millis(),
+            "Failed to flush buffered infinite memory events, will retry"
+        );
+        if requeued.dropped > 0 {
+            tracing::error!(
+                dropped = requeued.dropped,
+                "Infinite memory retry buffer full — dropped oldest events"
+            );
+        }
+        if requeued.needs_flush {
+            self.schedule_flush(delay);
+        }
+    }
+
+    /// [`Self::store_events`], falling back to one row at a time when a chunk
+    /// fails for any reason other than the database being unreachable. Such a
+    /// failure (e.g. a `\u0000` PostgreSQL rejects in jsonb) would recur on
+    /// every retry, so the offending events are logged and dropped instead.
+    /// Only an unavailable database is returned as an error.
+    async fn write_batch(
+        &self,
+        batch: Vec<RawInfiniteEvent>,
+    ) -> Result<(), (StorageError, Vec<RawInfiniteEvent>)> {
+        let (error, unwritten) = match self.store_events(batch).await {
+            Err((e, unwritten)) if !e.is_unavailable() => (e, unwritten),
+            result => return result,
+        };
+        tracing::warn!(
+            error = %error,
+            count = unwritten.len(),
+            "Infinite memory batch insert failed, storing events one by one"
+        );
+        let mut events = unwritten.into_iter();
+        while let Some(event) = events.next() {
+            let result = self
+                .guarded(|| {
+                    opencode_mem_storage::pg_storage::infinite_memory::store_infinite_events(
+                        &self.pool,
+                        std::slice::from_ref(&event),
+                    )
+                })
+                .await;
+            match result {
+                Ok(_) => {}
+                Err(e) if e.is_unavailable() => {
+                    let rest = std::iter::once(event).chain(events).collect();
+                    return Err((e, rest));
+                }
+                Err(e) => tracing::error!(
+                    error = %e,
+                    session_id = %event.session_id,
+                    event_type = event.event_type.as_str(),
+                    "Dropping infinite memory event the database rejected"
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `batch` in chunks of at most `max_events` rows. On failure returns the
+    /// error together with every event not yet written, in order.
+    async fn store_events(
+        &self,
+        mut batch: Vec<RawInfiniteEvent>,
+    ) -> Result<(), (StorageError, Vec<RawInfiniteEvent>)> {
+        let chunk_size = self
+            .buffer
+            .as_ref()
+            .map_or(buffer::MAX_BATCH_EVENTS, |b| b.max_events);
+        while !batch.is_empty() {
+            let rest = batch.split_off(chunk_size.min(batch.len()));
+            let result = self
+                .guarded(|| {
+                    opencode_mem_storage::pg_storage::infinite_memory::store_infinite_events(
+                        &self.pool, &batch,
+                    )
+                })
+                .await;
+            match result {
+                Ok(inserted) => {
+                    tracing::debug!(
+                        batch = batch.len(),
+                        inserted,
+                        "Flushed infinite memory event batch"
+                    );
+                    batch = rest;
+                }
+                Err(e) => {
+                    batch.extend(rest);
+                    return Err((e, batch));
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub async fn compress_events(
         &self,
         events: &[StoredInfiniteEvent],
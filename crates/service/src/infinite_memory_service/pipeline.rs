@@ -21,7 +21,27 @@ fn is_older_than_days(ts: &DateTime<Utc>, n: i64) -> bool {
     ts.signed_duration_since(Utc::now()).num_days() <= n.saturating_neg()
 }
 
+/// Compress unsummarized events into 5-minute summaries.
+///
+/// Runs under a Postgres advisory lock so concurrent runners (the server's
+/// periodic task, a manual CLI run, a second instance) never compress the same
+/// events twice. When the lock is already held this returns `Ok(0)` instead of
+/// waiting. `create_5min_summary` additionally refuses to link events that are
+/// already summarized, covering claims that outlived their visibility timeout.
 pub async fn run_compression_pipeline(pool: &PgPool, llm: &LlmClient) -> Result<u32> {
+    let Some(lock) = infinite_memory::try_acquire_compression_lock(pool)
+        .await
+        .map_err(anyhow::Error::from)?
+    else {
+        tracing::debug!("Compression already running elsewhere, skipping");
+        return Ok(0);
+    };
+    let result = compress_pending_events(pool, llm).await;
+    lock.release().await;
+    result
+}
+
+async fn compress_pending_events(pool: &PgPool, llm: &LlmClient) -> Result<u32> {
     let mut total_processed = 0u32;
     let batch_limit = i64::try_from(MAX_EVENTS_PER_BATCH)
         .map_err(|e| anyhow::anyhow!("MAX_EVENTS_PER_BATCH exceeds i64::MAX: {e}"))?;
@@ -134,8 +154,23 @@ pub async fn run_compression_pipeline(pool: &PgPool, llm: &LlmClient) -> Result<
     Ok(total_processed)
 }
 
+/// Run the full 5min → hour → day hierarchy under the same advisory lock as
+/// [`run_compression_pipeline`]. Returns zeros when another runner holds it.
 pub async fn run_full_compression(pool: &PgPool, llm: &LlmClient) -> Result<(u32, u32, u32)> {
-    let events_processed = run_compression_pipeline(pool, llm).await?;
+    let Some(lock) = infinite_memory::try_acquire_compression_lock(pool)
+        .await
+        .map_err(anyhow::Error::from)?
+    else {
+        tracing::debug!("Compression already running elsewhere, skipping");
+        return Ok((0, 0, 0));
+    };
+    let result = compress_hierarchy(pool, llm).await;
+    lock.release().await;
+    result
+}
+
+async fn compress_hierarchy(pool: &PgPool, llm: &LlmClient) -> Result<(u32, u32, u32)> {
+    let events_processed = compress_pending_events(pool, llm).await?;
 
     let sessions_5min = infinite_memory::get_sessions_with_unaggregated_5min(pool)
         .await
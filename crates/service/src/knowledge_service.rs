@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use opencode_mem_core::{
-    GlobalKnowledge, KnowledgeInput, KnowledgeSearchResult, KnowledgeType, cap_query_limit,
+    GlobalKnowledge, KnowledgeExportEntry, KnowledgeImportResult, KnowledgeInput,
+    KnowledgeSearchResult, KnowledgeType, cap_query_limit,
 };
 use opencode_mem_embeddings::LazyEmbeddingService;
 use opencode_mem_storage::traits::{EmbeddingStore, KnowledgeStore};
@@ -87,6 +88,41 @@ impl KnowledgeService {
         Ok(knowledge)
     }
 
+    /// Import exported entries through the same path as [`Self::save_knowledge`],
+    /// so each one is embedded and title-deduplicated against existing rows.
+    /// Entries with several source projects merge the extra projects into the
+    /// saved row to extend its provenance.
+    pub async fn import_knowledge(
+        &self,
+        entries: Vec<KnowledgeExportEntry>,
+    ) -> Result<KnowledgeImportResult, ServiceError> {
+        let mut result = KnowledgeImportResult::default();
+        for entry in entries {
+            let mut projects = entry.source_projects.iter().cloned();
+            let id = uuid::Uuid::new_v4().to_string();
+            let saved = self
+                .save_knowledge_with_id(&id, entry.to_input(projects.next()))
+                .await?;
+            if saved.id == id {
+                result.inserted = result.inserted.saturating_add(1);
+            } else {
+                result.merged = result.merged.saturating_add(1);
+            }
+            for project in projects {
+                let input = entry.to_input(Some(project));
+                let merged = self
+                    .storage
+                    .guarded(|| {
+                        self.storage
+                            .save_knowledge_with_id(&saved.id, input.clone())
+                    })
+                    .await;
+                self.with_cb(merged)?;
+            }
+        }
+        Ok(result)
+    }
+
     pub async fn delete_knowledge(&self, id: &str) -> Result<bool, ServiceError> {
         let result = self
             .storage
@@ -130,6 +166,57 @@ impl KnowledgeService {
         self.update_knowledge_usage_batch(&[id.to_owned()]).await
     }
 
+    /// Embed knowledge entries that have no vector yet, e.g. after the vector
+    /// columns were recreated for a new model or after an import.
+    /// Returns the number of embeddings stored.
+    pub async fn run_embedding_backfill(&self, batch_size: usize) -> Result<usize, ServiceError> {
+        let Some(ref embeddings) = self.embeddings else {
+            return Ok(0);
+        };
+        let mut total = 0_usize;
+        let mut failed_ids: Vec<String> = Vec::new();
+        loop {
+            let result = self
+                .storage
+                .guarded(|| {
+                    self.storage
+                        .get_knowledge_without_embeddings(batch_size, &failed_ids)
+                })
+                .await;
+            let batch = self.with_cb(result)?;
+            let batch_len = batch.len();
+            for knowledge in batch {
+                let text = format!("{} {}", knowledge.title.trim(), knowledge.description);
+                let embeddings_clone = Arc::clone(embeddings);
+                let embed_result = tokio::task::spawn_blocking(move || {
+                    use opencode_mem_embeddings::EmbeddingProvider;
+                    embeddings_clone.embed(&text)
+                })
+                .await;
+                let stored = match embed_result {
+                    Ok(Ok(vec)) => self
+                        .storage
+                        .guarded(|| self.storage.store_knowledge_embedding(&knowledge.id, &vec))
+                        .await
+                        .map_err(|e| e.to_string()),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match stored {
+                    Ok(()) => total = total.saturating_add(1),
+                    Err(e) => {
+                        tracing::warn!(id = %knowledge.id, error = %e, "Failed to embed knowledge");
+                        failed_ids.push(knowledge.id);
+                    }
+                }
+            }
+            if batch_len < batch_size {
+                break;
+            }
+        }
+        Ok(total)
+    }
+
     async fn generate_knowledge_embedding(&self, input: &KnowledgeInput) -> Option<Vec<f32>> {
         let embeddings = self.embeddings.as_ref()?;
         let text = format!("{} {}", input.title.trim(), input.description);
@@ -293,3 +380,7 @@ impl KnowledgeService {
         Ok((decayed, archived))
     }
 }
+
+#[cfg(test)]
+#[path = "knowledge_service_tests.rs"]
+mod tests;
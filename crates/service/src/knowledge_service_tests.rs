@@ -0,0 +1,52 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use super::*;
+use crate::test_support::pg_setup;
+
+#[tokio::test]
+#[ignore]
+async fn test_import_merges_existing_titles_and_inserts_new_ones() {
+    let (storage, _) = pg_setup().await;
+    let service = KnowledgeService::new(Arc::clone(&storage), None);
+    let tag = uuid::Uuid::new_v4().simple().to_string();
+    let title = format!("Import knowledge {tag}");
+
+    let input = KnowledgeInput::new(
+        KnowledgeType::Gotcha,
+        title.clone(),
+        format!("Description for gotcha {tag}"),
+        None,
+        vec!["import".to_owned()],
+        Some("import-test-project".to_owned()),
+        None,
+    );
+    let existing = service.save_knowledge(input).await.unwrap();
+    let entry = KnowledgeExportEntry::from(existing.clone());
+
+    // Unrelated title (own unique suffix) so trigram dedup cannot merge it.
+    let fresh_title = format!("Zebra crossing caveat {}", uuid::Uuid::new_v4().simple());
+    let mut fresh = entry.clone();
+    fresh.title = fresh_title.clone();
+    fresh.source_projects = vec!["import-a".to_owned(), "import-b".to_owned()];
+
+    let result = service.import_knowledge(vec![entry, fresh]).await.unwrap();
+    assert_eq!(result.merged, 1, "Existing title should merge");
+    assert_eq!(result.inserted, 1, "New title should insert");
+
+    let imported = storage
+        .export_knowledge()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|e| e.title == fresh_title)
+        .expect("Imported entry should be stored");
+    assert_eq!(imported.source_projects, vec!["import-a", "import-b"]);
+
+    sqlx::query("DELETE FROM global_knowledge WHERE id = $1 OR title = $2")
+        .bind(&existing.id)
+        .bind(&fresh_title)
+        .execute(&storage.pool())
+        .await
+        .unwrap();
+}
@@ -36,6 +36,8 @@ mod pending_write_queue;
 mod queue_service;
 mod search_service;
 mod session_service;
+#[cfg(test)]
+mod test_support;
 
 pub use error::ServiceError;
 pub use infinite_memory_service::InfiniteMemoryService;
@@ -49,9 +51,13 @@ pub use session_service::SessionService;
 
 // Re-export storage types used by HTTP handlers so they don't need direct storage dependency.
 pub use opencode_mem_storage::{
-    PaginatedResult, PendingMessage, QueueStats, StorageStats, default_visibility_timeout_secs,
+    PaginatedResult, PendingMessage, ProjectFileActivity, QueueStats, StorageStats,
+    default_visibility_timeout_secs,
 };
 
+// Re-export the embedding model state reported by health checks.
+pub use opencode_mem_embeddings::LoadState;
+
 // Re-export core infinite memory types for convenience.
 pub use opencode_mem_core::{
     InfiniteSummary, RawInfiniteEvent, StoredInfiniteEvent, SummaryEntities, tool_event,
@@ -0,0 +1,47 @@
+use opencode_mem_core::{Observation, ObservationType};
+
+use super::compression::fit_candidate_to_budget;
+
+fn long_candidate() -> Observation {
+    Observation::builder(
+        "id".to_owned(),
+        "session".to_owned(),
+        ObservationType::Discovery,
+        "Queue worker retries exhaust visibility timeout".to_owned(),
+    )
+    .subtitle("Retries outlive the lease")
+    .narrative("x".repeat(400))
+    .facts(vec!["y".repeat(100)])
+    .build()
+}
+
+#[test]
+fn test_zero_budget_keeps_full_candidate() {
+    let obs = fit_candidate_to_budget(long_candidate(), 0);
+    assert_eq!(obs.narrative.as_deref().map(str::len), Some(400));
+    assert_eq!(obs.facts.len(), 1);
+}
+
+#[test]
+fn test_candidate_within_budget_unchanged() {
+    let obs = fit_candidate_to_budget(long_candidate(), 500);
+    assert_eq!(obs.narrative.as_deref().map(str::len), Some(400));
+    assert_eq!(obs.facts.len(), 1);
+}
+
+#[test]
+fn test_candidate_over_budget_reduced_to_summary() {
+    let obs = fit_candidate_to_budget(long_candidate(), 100);
+    assert_eq!(obs.title, "Queue worker retries exhaust visibility timeout");
+    assert_eq!(obs.narrative.as_deref(), Some("Retries outlive the lease"));
+    assert!(obs.facts.is_empty());
+}
+
+#[test]
+fn test_candidate_without_subtitle_keeps_truncated_narrative() {
+    let mut candidate = long_candidate();
+    candidate.subtitle = None;
+    let obs = fit_candidate_to_budget(candidate, 100);
+    assert_eq!(obs.narrative.as_deref(), Some("x".repeat(100).as_str()));
+    assert!(obs.facts.is_empty());
+}
@@ -0,0 +1,108 @@
+//! Git commit capture — records commits reported by a `post-commit` hook.
+
+use opencode_mem_core::{CommitHookRequest, Observation, ObservationType, sanitize_input};
+
+use opencode_mem_storage::StorageError;
+use opencode_mem_storage::traits::ObservationStore;
+
+use super::{ObservationService, SaveMemoryResult};
+use crate::ServiceError;
+
+/// Session used when the hook does not attribute the commit to a session.
+const GIT_SESSION_ID: &str = "git";
+
+impl ObservationService {
+    /// Store a git commit as a `change` observation plus an infinite-memory
+    /// `commit` event.
+    ///
+    /// The observation id is derived from the SHA, so re-running the hook for
+    /// the same commit yields [`SaveMemoryResult::Duplicate`] of the stored
+    /// row. Commits bypass semantic dedup and carry the short SHA in their
+    /// title: two commits with the same subject are distinct changes.
+    pub async fn record_commit(
+        &self,
+        commit: &CommitHookRequest,
+    ) -> Result<SaveMemoryResult, ServiceError> {
+        let sha = commit.sha.trim();
+        if sha.is_empty() {
+            return Err(ServiceError::InvalidInput("sha is required".into()));
+        }
+        let message = sanitize_input(commit.message.trim());
+        let Some(subject) = message
+            .lines()
+            .next()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+        else {
+            return Err(ServiceError::InvalidInput(
+                "commit message is required".into(),
+            ));
+        };
+
+        let project = commit
+            .project
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+        if let Some(p) = project
+            && self.is_project_excluded(p)
+        {
+            tracing::info!(project = %p, "Skipping commit capture — project is excluded by privacy policy");
+            return Ok(SaveMemoryResult::Filtered);
+        }
+
+        let session_id = commit
+            .session_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .unwrap_or(GIT_SESSION_ID);
+        let files: Vec<String> = commit
+            .files
+            .iter()
+            .map(|f| f.trim())
+            .filter(|f| !f.is_empty())
+            .map(ToOwned::to_owned)
+            .collect();
+        let short_sha: String = sha.chars().take(12).collect();
+
+        let obs = Observation::builder(
+            format!("commit-{sha}"),
+            session_id.to_owned(),
+            ObservationType::Change,
+            format!("{subject} ({short_sha})"),
+        )
+        .maybe_project(project.map(Into::into))
+        .subtitle(format!("commit {short_sha}"))
+        .narrative(message.clone())
+        .files_modified(files.clone())
+        .build();
+
+        if let Some(ref infinite_mem) = self.infinite_mem {
+            let event = opencode_mem_core::commit_event(session_id, project, sha, &message, files);
+            if let Err(e) = infinite_mem.record_event(event).await {
+                tracing::warn!(error = %e, sha = %short_sha, "Failed to store commit event in infinite memory");
+            }
+        }
+
+        let embedding = self.generate_embedding(&obs).await;
+        if let Some((saved, true)) = self.save_and_notify(&obs, embedding).await? {
+            return Ok(SaveMemoryResult::Created(saved));
+        }
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_by_id(obs.id.as_ref()))
+            .await;
+        match self.with_cb(result)? {
+            Some(stored) => Ok(SaveMemoryResult::Duplicate(stored)),
+            None => Err(ServiceError::Storage(StorageError::NotFound {
+                entity: "observation",
+                id: obs.id.to_string(),
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "commit_tests.rs"]
+mod tests;
@@ -0,0 +1,112 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use std::sync::Arc;
+
+use opencode_mem_core::CommitHookRequest;
+use opencode_mem_llm::LlmClient;
+use opencode_mem_storage::StorageBackend;
+
+use super::*;
+use crate::InfiniteMemoryService;
+use crate::test_support::pg_setup;
+
+async fn setup() -> (ObservationService, Arc<StorageBackend>) {
+    let (storage, config) = pg_setup().await;
+    let llm = Arc::new(LlmClient::new(String::new(), String::new(), String::new()).unwrap());
+    let infinite_mem = InfiniteMemoryService::new(storage.pool(), Arc::clone(&llm))
+        .await
+        .unwrap();
+    let (event_tx, _) = tokio::sync::broadcast::channel(16);
+    let service = ObservationService::new(
+        Arc::clone(&storage),
+        llm,
+        Some(Arc::new(infinite_mem)),
+        event_tx,
+        None,
+        &config,
+    );
+    (service, storage)
+}
+
+fn commit(sha: &str, session_id: &str, message: &str) -> CommitHookRequest {
+    CommitHookRequest::new(
+        sha.to_owned(),
+        message.to_owned(),
+        vec!["src/lib.rs".to_owned()],
+        Some("commit-test".to_owned()),
+        Some(session_id.to_owned()),
+    )
+}
+
+async fn count(storage: &StorageBackend, sql: &str, key: &str) -> i64 {
+    sqlx::query_scalar(sql)
+        .bind(key)
+        .fetch_one(&storage.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_same_sha_twice_stores_one_row() {
+    let (service, storage) = setup().await;
+    let session_id = format!("commit-test-{}", uuid::Uuid::new_v4());
+    let sha = uuid::Uuid::new_v4().simple().to_string();
+    let req = commit(&sha, &session_id, "Fix typo in README");
+
+    let first = service.record_commit(&req).await.unwrap();
+    let SaveMemoryResult::Created(created) = first else {
+        panic!("first delivery must create the observation");
+    };
+    let second = service.record_commit(&req).await.unwrap();
+    let SaveMemoryResult::Duplicate(stored) = second else {
+        panic!("re-delivery must be a duplicate");
+    };
+    assert_eq!(stored.id, created.id);
+    assert_eq!(stored.title, created.title);
+
+    let rows = count(
+        &storage,
+        "SELECT COUNT(*) FROM observations WHERE session_id = $1",
+        &session_id,
+    )
+    .await;
+    assert_eq!(rows, 1);
+    let events = count(
+        &storage,
+        "SELECT COUNT(*) FROM raw_events WHERE session_id = $1",
+        &session_id,
+    )
+    .await;
+    assert_eq!(events, 1, "re-sent commit must not append another event");
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_commits_with_same_subject_are_distinct() {
+    let (service, storage) = setup().await;
+    let session_id = format!("commit-test-{}", uuid::Uuid::new_v4());
+    let subject = format!("WIP {}", uuid::Uuid::new_v4());
+
+    for _ in 0..2 {
+        let sha = uuid::Uuid::new_v4().simple().to_string();
+        let result = service
+            .record_commit(&commit(&sha, &session_id, &subject))
+            .await
+            .unwrap();
+        let SaveMemoryResult::Created(obs) = result else {
+            panic!("each commit must be stored");
+        };
+        assert_eq!(obs.id.as_ref(), format!("commit-{sha}"));
+        assert_eq!(obs.files_modified, vec!["src/lib.rs".to_owned()]);
+    }
+
+    let rows = count(
+        &storage,
+        "SELECT COUNT(*) FROM observations WHERE session_id = $1",
+        &session_id,
+    )
+    .await;
+    assert_eq!(rows, 2);
+}
@@ -4,7 +4,8 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use opencode_mem_core::{
-    Observation, ObservationInput, ToolCall, ToolOutput, is_trivial_tool_call, sanitize_input,
+    MergePreview, Observation, ObservationInput, ToolCall, ToolOutput, is_trivial_tool_call,
+    preview_merge, sanitize_input,
 };
 use opencode_mem_embeddings::EmbeddingProvider;
 use opencode_mem_llm::CompressionResult;
@@ -13,6 +14,99 @@ use opencode_mem_storage::traits::{ObservationStore, SearchStore};
 use super::ObservationService;
 use crate::ServiceError;
 
+/// Session id of observations saved by hand via `save_memory`.
+const MANUAL_SESSION_ID: &str = "manual";
+
+/// A context-aware merge is risky when it rewrites a hand-saved observation or
+/// drops the target's narrative entirely.
+pub(crate) fn is_risky_merge(target: &Observation, preview: &MergePreview) -> bool {
+    if preview.is_noop() {
+        return false;
+    }
+    target.session_id.as_ref() == MANUAL_SESSION_ID
+        || preview
+            .diff("narrative")
+            .is_some_and(|d| d.after.is_null() && !d.before.is_null())
+}
+
+/// When `require_narrative` is set and the LLM returned no narrative, build one
+/// from the observation's facts so search snippets and context injection have
+/// something to show.
+pub(crate) fn apply_narrative_requirement(require_narrative: bool, observation: &mut Observation) {
+    if !require_narrative
+        || observation
+            .narrative
+            .as_deref()
+            .is_some_and(|n| !n.trim().is_empty())
+    {
+        return;
+    }
+
+    let sentences: Vec<String> = observation
+        .facts
+        .iter()
+        .map(|f| f.trim())
+        .filter(|f| !f.is_empty())
+        .map(|f| {
+            if f.ends_with(['.', '!', '?']) {
+                f.to_owned()
+            } else {
+                format!("{f}.")
+            }
+        })
+        .collect();
+
+    if sentences.is_empty() {
+        tracing::warn!(
+            title = %observation.title,
+            "Narrative required but LLM returned neither narrative nor facts"
+        );
+        return;
+    }
+
+    tracing::debug!(title = %observation.title, "Synthesized narrative from facts");
+    observation.narrative = Some(sentences.join(" "));
+}
+
+/// Shrink a compression candidate to its summary form when its narrative and
+/// facts exceed `max_chars`: the subtitle, or the narrative truncated to
+/// `max_chars` when there is no subtitle. `0` leaves candidates untouched.
+///
+/// Only the copy sent to the LLM is trimmed; merges and merge-risk checks
+/// always see the stored observation.
+pub(crate) fn fit_candidate_to_budget(mut obs: Observation, max_chars: usize) -> Observation {
+    if max_chars == 0 {
+        return obs;
+    }
+    let content_chars = obs
+        .narrative
+        .as_deref()
+        .map_or(0, |n| n.chars().count())
+        .saturating_add(obs.facts.iter().map(|f| f.chars().count()).sum::<usize>());
+    if content_chars <= max_chars {
+        return obs;
+    }
+    obs.narrative = obs
+        .subtitle
+        .as_deref()
+        .or(obs.narrative.as_deref())
+        .map(|s| s.chars().take(max_chars).collect());
+    obs.facts.clear();
+    obs
+}
+
+/// Retry-dedup key for a tool call: the tool name plus whitespace-normalized
+/// input and output, so re-running the same command yields the same key.
+pub(crate) fn tool_call_dedup_key(tool: &str, input: &str, output: &str) -> String {
+    let normalize = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!(
+        "{}\u{1f}{}\u{1f}{}",
+        tool.trim().to_lowercase(),
+        normalize(input),
+        normalize(output)
+    )
+}
+
 impl ObservationService {
     pub async fn compress_and_save(
         &self,
@@ -37,6 +131,23 @@ impl ObservationService {
             })
         };
 
+        let dedup_key = tool_call_dedup_key(
+            &tool_call.tool,
+            &filtered_input.to_string(),
+            &filtered_output,
+        );
+        if let Some(existing) = self
+            .find_tool_retry(tool_call.session_id.as_ref(), &dedup_key)
+            .await
+        {
+            tracing::info!(
+                tool = %tool_call.tool,
+                existing_id = %existing.id,
+                "Identical tool call already observed in this session, skipping LLM compression"
+            );
+            return Ok(Some((existing, false)));
+        }
+
         let input = ObservationInput::new(
             tool_call.tool.clone(),
             tool_call.session_id.clone(),
@@ -62,11 +173,36 @@ impl ObservationService {
             )
             .await;
 
+        let prompt_candidates: Vec<Observation> = candidates
+            .iter()
+            .cloned()
+            .map(|obs| fit_candidate_to_budget(obs, self.max_candidate_chars))
+            .collect();
         let compression_result = self
             .llm
-            .compress_to_observation(id, &input, parsed_project, &candidates)
+            .compress_to_observation(id, &input, parsed_project, &prompt_candidates)
             .await?;
 
+        let saved = self
+            .save_compression_result(compression_result, tool_call, &candidates)
+            .await?;
+        // A merge can land in another session's observation; the key only
+        // means something within the session that made the call.
+        if let Some((ref obs, _)) = saved
+            && obs.session_id == tool_call.session_id
+        {
+            self.record_tool_call_hash(obs.id.as_ref(), &dedup_key)
+                .await;
+        }
+        Ok(saved)
+    }
+
+    async fn save_compression_result(
+        &self,
+        compression_result: CompressionResult,
+        tool_call: &ToolCall,
+        candidates: &[Observation],
+    ) -> Result<Option<(Observation, bool)>, ServiceError> {
         match compression_result {
             CompressionResult::Skip { reason } => {
                 tracing::debug!(reason = %reason, "Observation skipped by LLM");
@@ -74,6 +210,10 @@ impl ObservationService {
             }
             CompressionResult::Create(mut observation) => {
                 observation.title = sanitize_input(&observation.title);
+                apply_narrative_requirement(self.require_narrative, &mut observation);
+                self.apply_transient_ttl(&mut observation);
+                self.stamp_prompt_number(&mut observation, tool_call.session_id.as_ref())
+                    .await;
                 self.persist_and_notify(&observation, Some(tool_call.session_id.as_ref()))
                     .await
             }
@@ -82,9 +222,38 @@ impl ObservationService {
                 mut observation,
             } => {
                 observation.title = sanitize_input(&observation.title);
+                apply_narrative_requirement(self.require_narrative, &mut observation);
+                self.apply_transient_ttl(&mut observation);
+                self.stamp_prompt_number(&mut observation, tool_call.session_id.as_ref())
+                    .await;
                 let candidate_ids: HashSet<&str> =
                     candidates.iter().map(|o| o.id.as_ref()).collect();
 
+                if self.merge_require_confirm && candidate_ids.contains(target_id.as_str()) {
+                    // Judge the merge against the stored row, not the candidate
+                    // copy the LLM saw, so nothing it omitted can hide a loss.
+                    let result = self
+                        .storage
+                        .guarded(|| self.storage.get_by_id(&target_id))
+                        .await;
+                    if let Some(target) = self.with_cb(result)? {
+                        let preview = preview_merge(&target, &observation, true);
+                        if is_risky_merge(&target, &preview) {
+                            tracing::warn!(
+                                target_id = %target_id,
+                                preview = %serde_json::to_string(&preview).unwrap_or_default(),
+                                "Risky context-aware merge skipped, saving as new (OPENCODE_MEM_MERGE_REQUIRE_CONFIRM)"
+                            );
+                            return self
+                                .persist_and_notify(
+                                    &observation,
+                                    Some(tool_call.session_id.as_ref()),
+                                )
+                                .await;
+                        }
+                    }
+                }
+
                 if !candidate_ids.contains(target_id.as_str()) {
                     tracing::warn!(
                         target_id = %target_id,
@@ -137,6 +306,49 @@ impl ObservationService {
         }
     }
 
+    /// Dry run of a context-aware update: the field changes merging
+    /// `new_observation` into `target_id` would make, without applying them.
+    pub async fn preview_merge(
+        &self,
+        target_id: &str,
+        new_observation: &Observation,
+    ) -> Result<MergePreview, ServiceError> {
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_by_id(target_id))
+            .await;
+        let target = self.with_cb(result)?.ok_or_else(|| {
+            ServiceError::Storage(opencode_mem_storage::StorageError::NotFound {
+                entity: "observation",
+                id: target_id.to_owned(),
+            })
+        })?;
+        Ok(preview_merge(&target, new_observation, true))
+    }
+
+    /// Look up an observation this session already produced for the same tool
+    /// call. Lookup failures only disable the shortcut.
+    async fn find_tool_retry(&self, session_id: &str, dedup_key: &str) -> Option<Observation> {
+        let result = self
+            .storage
+            .guarded(|| self.storage.find_by_tool_call_hash(session_id, dedup_key))
+            .await;
+        self.with_cb(result).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Tool retry lookup failed, proceeding with compression");
+            None
+        })
+    }
+
+    async fn record_tool_call_hash(&self, observation_id: &str, dedup_key: &str) {
+        let result = self
+            .storage
+            .guarded(|| self.storage.set_tool_call_hash(observation_id, dedup_key))
+            .await;
+        if let Err(e) = self.with_cb(result) {
+            tracing::warn!(id = %observation_id, error = %e, "Failed to record tool call hash");
+        }
+    }
+
     /// Build a search query from tool input + output, taking head+tail to capture
     /// both the beginning context and the final results of the tool call.
     fn build_candidate_query(tool_input: &str, tool_output: &str, max_len: usize) -> String {
@@ -281,6 +493,10 @@ impl ObservationService {
             }
         }
 
+        if self.max_candidates > 0 {
+            result.truncate(self.max_candidates);
+        }
+
         if !result.is_empty() {
             tracing::debug!(
                 count = result.len(),
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use opencode_mem_core::{
+    AppConfig, Observation, ObservationType, SessionId, ToolCall, preview_merge,
+};
+use opencode_mem_llm::LlmClient;
+use opencode_mem_storage::traits::ObservationStore;
+
+use super::ObservationService;
+use super::compression::is_risky_merge;
+use crate::test_support::{mock_llm, pg_setup};
+
+fn observation(session_id: &str, narrative: Option<&str>) -> Observation {
+    Observation::builder(
+        "target".to_owned(),
+        session_id.to_owned(),
+        ObservationType::Decision,
+        "Use advisory locks for the dedup sweep".to_owned(),
+    )
+    .maybe_narrative(narrative.map(ToOwned::to_owned))
+    .build()
+}
+
+#[test]
+fn test_merge_into_manual_observation_is_risky() {
+    let target = observation("manual", Some("Curated by hand"));
+    let update = observation("session-1", Some("LLM rewrite"));
+    let preview = preview_merge(&target, &update, true);
+    assert!(is_risky_merge(&target, &preview));
+}
+
+#[test]
+fn test_merge_dropping_narrative_is_risky() {
+    let target = observation("session-1", Some("Existing narrative"));
+    let update = observation("session-1", None);
+    let preview = preview_merge(&target, &update, true);
+    assert!(is_risky_merge(&target, &preview));
+}
+
+#[test]
+fn test_ordinary_update_is_not_risky() {
+    let target = observation("session-1", Some("Old narrative"));
+    let update = observation("session-1", Some("Refined narrative"));
+    let preview = preview_merge(&target, &update, true);
+    assert!(!is_risky_merge(&target, &preview));
+
+    let manual = observation("manual", Some("Curated by hand"));
+    let noop = preview_merge(&manual, &manual.clone(), true);
+    assert!(!is_risky_merge(&manual, &noop));
+}
+
+// Requires a running PostgreSQL instance; see `test_support`.
+#[tokio::test]
+#[ignore]
+async fn test_risky_merge_is_saved_as_new_observation() {
+    let (storage, config) = pg_setup().await;
+    let tag = uuid::Uuid::new_v4().simple().to_string();
+    let project = format!("risky_merge_{tag}");
+    let target = Observation::builder(
+        uuid::Uuid::new_v4().to_string(),
+        "manual".to_owned(),
+        ObservationType::Decision,
+        format!("Use advisory locks marker{tag}"),
+    )
+    .project(project.as_str())
+    .narrative("Curated by hand".to_owned())
+    .build();
+    storage.save_observation(&target).await.unwrap();
+
+    let server = mock_llm(vec![
+        serde_json::json!({
+            "action": "update",
+            "target_id": target.id.as_ref(),
+            "noise_level": "high",
+            "type": "decision",
+            "title": format!("Use advisory locks marker{tag}"),
+            "narrative": "LLM rewrite of the curated note.",
+            "facts": [],
+        })
+        .to_string(),
+    ])
+    .await;
+    let llm = LlmClient::new("test-key".to_owned(), server.uri(), "test-model".to_owned()).unwrap();
+    let (event_tx, _) = tokio::sync::broadcast::channel(16);
+    let config = AppConfig {
+        merge_require_confirm: true,
+        ..config
+    };
+    let service = ObservationService::new(
+        Arc::clone(&storage),
+        Arc::new(llm),
+        None,
+        event_tx,
+        None,
+        &config,
+    );
+
+    let session_id = format!("risky-merge-{tag}");
+    let tool_call = ToolCall::new(
+        "bash".to_owned(),
+        SessionId(session_id.clone()),
+        "call-1".to_owned(),
+        Some(project.clone()),
+        serde_json::json!({ "command": "cargo test" }),
+        format!("marker{tag} locks acquired"),
+    );
+    let (saved, created) = service
+        .compress_and_save(&uuid::Uuid::new_v4().to_string(), &tool_call)
+        .await
+        .unwrap()
+        .expect("skipped merge should still save the observation");
+    assert!(created);
+    assert_ne!(saved.id, target.id);
+    assert_eq!(saved.session_id.as_ref(), session_id);
+
+    let stored = storage
+        .get_by_id(target.id.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.narrative.as_deref(), Some("Curated by hand"));
+}
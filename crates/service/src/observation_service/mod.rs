@@ -1,3 +1,4 @@
+mod commit;
 mod compression;
 mod dedup_sweep;
 mod injection;
@@ -33,6 +34,11 @@ pub struct ObservationService {
     pub(crate) project_filter: Option<opencode_mem_core::ProjectFilter>,
     pub(crate) low_value_filter: opencode_mem_core::LowValueFilter,
     pub(crate) enrichment_semaphore: Arc<Semaphore>,
+    pub(crate) transient_ttl: Option<chrono::Duration>,
+    pub(crate) require_narrative: bool,
+    pub(crate) max_candidates: usize,
+    pub(crate) max_candidate_chars: usize,
+    pub(crate) merge_require_confirm: bool,
 }
 
 impl ObservationService {
@@ -47,6 +53,16 @@ impl ObservationService {
         result.map_err(crate::ServiceError::from)
     }
 
+    /// Circuit breaker guarding LLM calls.
+    pub fn llm_circuit_breaker(&self) -> &opencode_mem_llm::LlmCircuitBreaker {
+        self.llm.circuit_breaker()
+    }
+
+    /// LLM reachability probe for health checks.
+    pub async fn probe_llm(&self) -> Result<(), crate::ServiceError> {
+        self.llm.probe().await.map_err(crate::ServiceError::from)
+    }
+
     pub fn update_llm_config(
         &self,
         api_key: Option<String>,
@@ -56,6 +72,15 @@ impl ObservationService {
         self.llm.update_config(api_key, base_url, model);
     }
 
+    /// Returns a copy of this service whose LLM calls use `model` instead of
+    /// the configured default. Used for per-request model overrides.
+    #[must_use]
+    pub fn with_llm_model(&self, model: String) -> Self {
+        let mut svc = self.clone();
+        svc.llm = Arc::new(self.llm.as_ref().clone().with_model(model));
+        svc
+    }
+
     #[must_use]
     pub fn new(
         storage: Arc<StorageBackend>,
@@ -71,6 +96,9 @@ impl ObservationService {
             opencode_mem_core::ProjectFilter::new(config.excluded_projects_raw.as_deref());
         let low_value_filter =
             opencode_mem_core::LowValueFilter::new(config.filter_patterns_raw.as_deref());
+        let transient_ttl = (config.transient_ttl_hours > 0)
+            .then(|| i64::try_from(config.transient_ttl_hours).unwrap_or(i64::MAX))
+            .and_then(chrono::Duration::try_hours);
         if injection_dedup_threshold > 0.0 && embeddings.is_none() {
             tracing::warn!(
                 threshold = %injection_dedup_threshold,
@@ -88,6 +116,11 @@ impl ObservationService {
             project_filter,
             low_value_filter,
             enrichment_semaphore: Arc::new(Semaphore::new(3)),
+            transient_ttl,
+            require_narrative: config.require_narrative,
+            max_candidates: config.max_candidates,
+            max_candidate_chars: config.max_candidate_chars,
+            merge_require_confirm: config.merge_require_confirm,
         }
     }
 
@@ -165,4 +198,12 @@ impl ObservationService {
 
 mod adversarial_tests;
 #[cfg(test)]
+mod candidate_budget_tests;
+#[cfg(test)]
+mod merge_preview_tests;
+#[cfg(test)]
+mod narrative_tests;
+#[cfg(test)]
 mod privacy_tests;
+#[cfg(test)]
+mod tool_retry_tests;
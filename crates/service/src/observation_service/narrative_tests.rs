@@ -0,0 +1,47 @@
+use opencode_mem_core::{Observation, ObservationType};
+
+use super::compression::apply_narrative_requirement;
+
+fn null_narrative_observation() -> Observation {
+    Observation::builder(
+        "id".to_owned(),
+        "session".to_owned(),
+        ObservationType::Gotcha,
+        "sqlx rejects empty arrays without a type cast".to_owned(),
+    )
+    .facts(vec![
+        "Binding an empty Vec<String> needs an explicit ::text[] cast".to_owned(),
+        "Without it Postgres reports an unknown parameter type.".to_owned(),
+    ])
+    .build()
+}
+
+#[test]
+fn test_required_narrative_synthesized_from_facts() {
+    let mut obs = null_narrative_observation();
+    assert!(obs.narrative.is_none());
+
+    apply_narrative_requirement(true, &mut obs);
+
+    let narrative = obs.narrative.expect("narrative should be synthesized");
+    assert_eq!(
+        narrative,
+        "Binding an empty Vec<String> needs an explicit ::text[] cast. \
+         Without it Postgres reports an unknown parameter type."
+    );
+}
+
+#[test]
+fn test_null_narrative_accepted_when_not_required() {
+    let mut obs = null_narrative_observation();
+    apply_narrative_requirement(false, &mut obs);
+    assert!(obs.narrative.is_none());
+}
+
+#[test]
+fn test_existing_narrative_preserved() {
+    let mut obs = null_narrative_observation();
+    obs.narrative = Some("Original narrative".to_owned());
+    apply_narrative_requirement(true, &mut obs);
+    assert_eq!(obs.narrative.as_deref(), Some("Original narrative"));
+}
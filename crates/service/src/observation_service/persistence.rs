@@ -1,5 +1,5 @@
-use opencode_mem_core::Observation;
-use opencode_mem_storage::traits::{EmbeddingStore, ObservationStore};
+use opencode_mem_core::{Observation, PromptNumber};
+use opencode_mem_storage::traits::{EmbeddingStore, ObservationStore, SessionStore};
 
 use super::ObservationService;
 use crate::ServiceError;
@@ -17,6 +17,53 @@ impl ObservationService {
         Ok(deleted)
     }
 
+    /// Delete observations past their `expires_at`. Returns the number removed.
+    pub async fn expire_observations(&self) -> Result<usize, ServiceError> {
+        let result = self
+            .storage
+            .guarded(|| self.storage.expire_observations())
+            .await;
+        let expired = self.with_cb(result)?;
+        if expired > 0 {
+            tracing::info!(expired, "Expired transient observations");
+        }
+        Ok(expired)
+    }
+
+    /// Stamp the configured transient TTL onto low-value observations that
+    /// don't already carry an explicit expiry.
+    pub(crate) fn apply_transient_ttl(&self, observation: &mut Observation) {
+        if observation.expires_at.is_none()
+            && observation.noise_level.is_transient()
+            && let Some(ttl) = self.transient_ttl
+        {
+            observation.expires_at = observation.created_at.checked_add_signed(ttl);
+        }
+    }
+
+    /// Link an observation to the prompt it was produced under by stamping the
+    /// latest prompt number of its content session. Lookup failures leave it
+    /// unlinked.
+    pub(crate) async fn stamp_prompt_number(
+        &self,
+        observation: &mut Observation,
+        session_id: &str,
+    ) {
+        if observation.prompt_number.is_some() {
+            return;
+        }
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_current_prompt_number(session_id))
+            .await;
+        match self.with_cb(result) {
+            Ok(number) => observation.prompt_number = number.map(PromptNumber),
+            Err(e) => {
+                tracing::warn!(session_id = %session_id, error = %e, "Failed to look up prompt number");
+            }
+        }
+    }
+
     pub async fn save_observation(&self, observation: &Observation) -> Result<(), ServiceError> {
         let _result = self.persist_and_notify(observation, None).await?;
         Ok(())
@@ -171,7 +218,7 @@ impl ObservationService {
         }
     }
 
-    async fn save_and_notify(
+    pub(crate) async fn save_and_notify(
         &self,
         observation: &Observation,
         embedding_vec: Option<Vec<f32>>,
@@ -185,22 +232,30 @@ impl ObservationService {
                 .storage
                 .guarded(|| self.storage.save_observation(&obs))
                 .await;
-            match self.with_cb(result) {
-                Ok(is_new) => {
-                    inserted = is_new;
-                    last_was_title_collision = false;
-                    break;
-                }
-                Err(ServiceError::Storage(opencode_mem_storage::StorageError::Duplicate(msg))) => {
-                    tracing::warn!(
-                        "Title collision (23505): {}, mutating title and retrying",
-                        msg
-                    );
-                    obs.title = format!("{} ({})", observation.title, i.saturating_add(1));
-                    last_was_title_collision = true;
-                }
-                Err(e) => return Err(e),
+            let is_new = self.with_cb(result)?;
+            if is_new {
+                inserted = true;
+                last_was_title_collision = false;
+                break;
+            }
+
+            // `false` means either this ID already exists (idempotent retry) or
+            // another row owns the same normalized title.
+            let result = self
+                .storage
+                .guarded(|| self.storage.get_by_id(obs.id.as_ref()))
+                .await;
+            if self.with_cb(result)?.is_some() {
+                last_was_title_collision = false;
+                break;
             }
+
+            tracing::warn!(
+                "Title collision on '{}', mutating title and retrying",
+                obs.title
+            );
+            obs.title = format!("{} ({})", observation.title, i.saturating_add(1));
+            last_was_title_collision = true;
         }
 
         if !inserted && last_was_title_collision {
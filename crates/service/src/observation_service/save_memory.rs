@@ -48,13 +48,10 @@ impl ObservationService {
         // but ProjectId::new() normalizes (lowercase, hyphens→underscores, trim slashes).
         // Without pre-normalization, "My-Secret/" bypasses a pattern for "my_secret".
         if let Some(p) = project_trimmed
-            && let Some(ref filter) = self.project_filter
+            && self.is_project_excluded(p)
         {
-            let normalized = opencode_mem_core::ProjectId::new(p).to_string();
-            if filter.is_excluded(&normalized) {
-                tracing::info!(project = %p, normalized = %normalized, "Skipping save_memory — project is excluded by privacy policy");
-                return Ok(SaveMemoryResult::Filtered);
-            }
+            tracing::info!(project = %p, "Skipping save_memory — project is excluded by privacy policy");
+            return Ok(SaveMemoryResult::Filtered);
         }
 
         let title_str = match title {
@@ -88,7 +85,7 @@ impl ObservationService {
                 vec![],
                 None,
             );
-            if let Err(e) = infinite_mem.store_event(event).await {
+            if let Err(e) = infinite_mem.record_event(event).await {
                 tracing::warn!(error = %e, "Failed to store manual save_memory event in infinite memory");
             }
         }
@@ -103,6 +100,35 @@ impl ObservationService {
         }
     }
 
+    /// Persist a caller-built observation as-is: no LLM compression or enrichment,
+    /// but the usual privacy filter, semantic dedup and embedding still apply.
+    pub async fn save_direct(&self, obs: Observation) -> Result<SaveMemoryResult, ServiceError> {
+        if obs.title.trim().is_empty() {
+            return Err(ServiceError::InvalidInput(
+                "title is required for a direct observation".into(),
+            ));
+        }
+        if let Some(ref p) = obs.project
+            && self.is_project_excluded(p.as_ref())
+        {
+            tracing::info!(project = %p, "Skipping direct observation — project is excluded by privacy policy");
+            return Ok(SaveMemoryResult::Filtered);
+        }
+
+        match self.persist_and_notify(&obs, None).await? {
+            Some((persisted_obs, true)) => Ok(SaveMemoryResult::Created(persisted_obs)),
+            Some((merged_obs, false)) => Ok(SaveMemoryResult::Duplicate(merged_obs)),
+            None => Ok(SaveMemoryResult::Duplicate(obs)),
+        }
+    }
+
+    /// Whether `project` matches the privacy exclusion filter.
+    pub(crate) fn is_project_excluded(&self, project: &str) -> bool {
+        self.project_filter.as_ref().is_some_and(|filter| {
+            filter.is_excluded(opencode_mem_core::ProjectId::new(project).as_ref())
+        })
+    }
+
     fn spawn_enrichment(&self, obs: Observation) {
         let llm = self.llm.clone();
         let storage = self.storage.clone();
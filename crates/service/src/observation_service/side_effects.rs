@@ -94,7 +94,7 @@ impl ObservationService {
                 files_modified,
                 Some(tool_call.call_id.clone()),
             );
-            if let Err(e) = infinite_mem.store_event(event).await {
+            if let Err(e) = infinite_mem.record_event(event).await {
                 tracing::warn!(error = %e, observation_id = %obs_id_for_log, "Failed to store in infinite memory — event will be missing from long-term history");
                 return Err(crate::ServiceError::System(e.into()));
             }
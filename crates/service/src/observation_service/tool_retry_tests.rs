@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use opencode_mem_core::{Observation, ObservationType, SessionId, ToolCall};
+use opencode_mem_llm::LlmClient;
+use opencode_mem_storage::traits::ObservationStore;
+
+use super::ObservationService;
+use super::compression::tool_call_dedup_key;
+use crate::test_support::{mock_llm, pg_setup};
+
+#[test]
+fn test_dedup_key_ignores_whitespace_differences() {
+    let first = tool_call_dedup_key("bash", r#"{"command":"cargo test"}"#, "ok\n  2 passed\n");
+    let retry = tool_call_dedup_key("Bash", r#"{"command":"cargo test"}"#, "ok 2 passed");
+    assert_eq!(first, retry);
+}
+
+#[test]
+fn test_dedup_key_distinguishes_output() {
+    let passed = tool_call_dedup_key("bash", r#"{"command":"cargo test"}"#, "2 passed");
+    let failed = tool_call_dedup_key("bash", r#"{"command":"cargo test"}"#, "1 failed");
+    assert_ne!(passed, failed);
+}
+
+#[test]
+fn test_dedup_key_separates_fields() {
+    let a = tool_call_dedup_key("bash", "ab", "c");
+    let b = tool_call_dedup_key("bash", "a", "bc");
+    assert_ne!(a, b);
+}
+
+fn compression_reply(title: &str, narrative: &str) -> String {
+    serde_json::json!({
+        "action": "create",
+        "noise_level": "high",
+        "type": "bugfix",
+        "title": title,
+        "narrative": narrative,
+        "facts": [],
+    })
+    .to_string()
+}
+
+// Requires a running PostgreSQL instance; see `test_support`.
+#[tokio::test]
+#[ignore]
+async fn test_identical_retry_reuses_observation_despite_reworded_llm_output() {
+    let (storage, config) = pg_setup().await;
+    let tag = uuid::Uuid::new_v4().simple().to_string();
+    // Two differently worded results: were the retry compressed again, the
+    // second title would not collide with the first and a second row would land.
+    let server = mock_llm(vec![
+        compression_reply(
+            &format!("Fixed E0308 in parser {tag}"),
+            "Changed the return type of parse_header to Result.",
+        ),
+        compression_reply(
+            &format!("Resolved mismatched types in header parsing {tag}"),
+            "parse_header now returns a Result instead of panicking.",
+        ),
+    ])
+    .await;
+    let llm = LlmClient::new("test-key".to_owned(), server.uri(), "test-model".to_owned()).unwrap();
+    let (event_tx, _) = tokio::sync::broadcast::channel(16);
+    let service = ObservationService::new(
+        Arc::clone(&storage),
+        Arc::new(llm),
+        None,
+        event_tx,
+        None,
+        &config,
+    );
+
+    let session_id = format!("retry-test-{tag}");
+    let tool_call = |call_id: &str| {
+        ToolCall::new(
+            "bash".to_owned(),
+            SessionId(session_id.clone()),
+            call_id.to_owned(),
+            None,
+            serde_json::json!({ "command": "cargo build" }),
+            "error[E0308]: mismatched types\n --> src/parser.rs:42:5".to_owned(),
+        )
+    };
+
+    let first_id = uuid::Uuid::new_v4().to_string();
+    let (first, created) = service
+        .compress_and_save(&first_id, &tool_call("call-1"))
+        .await
+        .unwrap()
+        .expect("first call should be stored");
+    assert!(created);
+
+    let retry_id = uuid::Uuid::new_v4().to_string();
+    let (retry, created) = service
+        .compress_and_save(&retry_id, &tool_call("call-2"))
+        .await
+        .unwrap()
+        .expect("retry should resolve to the stored observation");
+    assert!(!created);
+    assert_eq!(retry.id, first.id);
+    let completions = server.received_requests().await.unwrap();
+    assert_eq!(completions.len(), 1);
+
+    let rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM observations WHERE session_id = $1")
+        .bind(&session_id)
+        .fetch_one(&storage.pool())
+        .await
+        .unwrap();
+    assert_eq!(rows, 1);
+}
+
+// Requires a running PostgreSQL instance; see `test_support`.
+#[tokio::test]
+#[ignore]
+async fn test_merge_into_other_session_does_not_record_tool_call_hash() {
+    let (storage, config) = pg_setup().await;
+    let tag = uuid::Uuid::new_v4().simple().to_string();
+    let project = format!("retry_merge_{tag}");
+    let target = Observation::builder(
+        uuid::Uuid::new_v4().to_string(),
+        format!("other-session-{tag}"),
+        ObservationType::Bugfix,
+        format!("Parser fails on marker{tag}"),
+    )
+    .project(project.as_str())
+    .narrative(format!("The parser rejects marker{tag} headers."))
+    .build();
+    storage.save_observation(&target).await.unwrap();
+
+    let server = mock_llm(vec![
+        serde_json::json!({
+            "action": "update",
+            "target_id": target.id.as_ref(),
+            "noise_level": "high",
+            "type": "bugfix",
+            "title": format!("Parser fails on marker{tag}"),
+            "narrative": format!("marker{tag} headers now parse."),
+            "facts": [],
+        })
+        .to_string(),
+    ])
+    .await;
+    let llm = LlmClient::new("test-key".to_owned(), server.uri(), "test-model".to_owned()).unwrap();
+    let (event_tx, _) = tokio::sync::broadcast::channel(16);
+    let service = ObservationService::new(
+        Arc::clone(&storage),
+        Arc::new(llm),
+        None,
+        event_tx,
+        None,
+        &config,
+    );
+
+    let tool_call = ToolCall::new(
+        "bash".to_owned(),
+        SessionId(format!("retry-test-{tag}")),
+        "call-1".to_owned(),
+        Some(project.clone()),
+        serde_json::json!({ "command": "cargo test" }),
+        format!("marker{tag} parsed"),
+    );
+    let (merged, created) = service
+        .compress_and_save(&uuid::Uuid::new_v4().to_string(), &tool_call)
+        .await
+        .unwrap()
+        .expect("update should merge into the target");
+    assert!(!created);
+    assert_eq!(merged.id, target.id);
+
+    let hash: Option<String> =
+        sqlx::query_scalar("SELECT tool_call_hash FROM observations WHERE id = $1")
+            .bind(target.id.as_ref())
+            .fetch_one(&storage.pool())
+            .await
+            .unwrap();
+    assert_eq!(hash, None);
+}
@@ -54,9 +54,12 @@ impl QueueService {
     /// Applies `ProjectFilter` check and `sanitize_input` on tool input/output
     /// before inserting into the pending queue. Returns `ExcludedProject` if the
     /// tool call's project is excluded, so callers can skip without error.
+    /// `model` is stored with the message and overrides the LLM model when it
+    /// is processed.
     pub async fn queue_tool_call(
         &self,
         tool_call: &ToolCall,
+        model: Option<&str>,
     ) -> Result<QueueToolCallResult, ServiceError> {
         if let Some(project) = tool_call.project.as_deref()
             && self.is_project_excluded(Some(project))
@@ -81,6 +84,7 @@ impl QueueService {
                     tool_input_str.as_deref(),
                     Some(&filtered_output),
                     tool_call.project.as_deref(),
+                    model,
                 )
             })
             .await;
@@ -174,6 +178,7 @@ impl QueueService {
                     tool_input,
                     tool_response,
                     project,
+                    None,
                 )
             })
             .await;
@@ -231,6 +236,25 @@ impl QueueService {
         self.with_cb(result.map_err(ServiceError::from))
     }
 
+    /// Settles a message whose processing failed.
+    ///
+    /// While the LLM circuit breaker is open the message was never really
+    /// attempted, so it goes back to pending without consuming a retry;
+    /// any other error goes through [`Self::fail_message`]. Returns whether
+    /// the message was deferred.
+    pub async fn fail_or_defer_message(
+        &self,
+        id: i64,
+        error: &ServiceError,
+    ) -> Result<bool, ServiceError> {
+        if error.llm_circuit_retry_after().is_some() {
+            self.release_messages(&[id]).await?;
+            return Ok(true);
+        }
+        self.fail_message(id, false).await?;
+        Ok(false)
+    }
+
     pub async fn clear_failed_messages(&self) -> Result<usize, ServiceError> {
         let result = self
             .storage
@@ -290,3 +314,7 @@ impl QueueService {
         self.with_cb(result.map_err(ServiceError::from))
     }
 }
+
+#[cfg(test)]
+#[path = "queue_service_tests.rs"]
+mod tests;
@@ -0,0 +1,91 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use std::time::Duration;
+
+use super::*;
+use crate::ObservationService;
+use crate::test_support::pg_setup;
+use opencode_mem_core::SessionId;
+use opencode_mem_llm::{LlmCircuitBreaker, LlmClient};
+
+async fn retry_state(storage: &StorageBackend, id: i64) -> (String, i32) {
+    sqlx::query_as("SELECT status, retry_count FROM pending_messages WHERE id = $1")
+        .bind(id)
+        .fetch_one(&storage.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_open_llm_breaker_defers_without_consuming_retry() {
+    let (storage, config) = pg_setup().await;
+    let session_id = format!("breaker-test-{}", uuid::Uuid::new_v4());
+    let input = r#"{"command":"cargo build --release"}"#;
+    let output = "error[E0308]: mismatched types in src/main.rs";
+
+    let queue = QueueService::new(
+        Arc::clone(&storage),
+        Arc::new(PendingWriteQueue::new()),
+        &config,
+    );
+    let msg_id = storage
+        .queue_message(
+            &session_id,
+            None,
+            Some("bash"),
+            Some(input),
+            Some(output),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let claimed = queue.claim_pending_messages(100, 300).await.unwrap();
+    assert!(claimed.iter().any(|m| m.id == msg_id));
+
+    let breaker = LlmCircuitBreaker::new(1, Duration::from_secs(60), Duration::from_secs(60));
+    breaker.record_failure();
+    let llm = LlmClient::new(String::new(), String::new(), String::new())
+        .unwrap()
+        .with_circuit_breaker(breaker);
+    let (event_tx, _) = tokio::sync::broadcast::channel(16);
+    let observations = ObservationService::new(
+        Arc::clone(&storage),
+        Arc::new(llm),
+        None,
+        event_tx,
+        None,
+        &config,
+    );
+
+    let call_id = uuid::Uuid::new_v4().to_string();
+    let tool_call = ToolCall::new(
+        "bash".to_owned(),
+        SessionId(session_id.clone()),
+        call_id.clone(),
+        None,
+        serde_json::from_str(input).unwrap(),
+        output.to_owned(),
+    );
+    let err = observations
+        .process(&call_id, tool_call)
+        .await
+        .expect_err("open breaker must fail fast");
+    assert!(err.llm_circuit_retry_after().is_some(), "got {err}");
+
+    assert!(queue.fail_or_defer_message(msg_id, &err).await.unwrap());
+    assert_eq!(
+        retry_state(&storage, msg_id).await,
+        ("pending".to_owned(), 0),
+        "an open breaker must not burn the message's retries"
+    );
+
+    let other = ServiceError::InvalidInput("bad payload".to_owned());
+    queue.claim_pending_messages(100, 300).await.unwrap();
+    assert!(!queue.fail_or_defer_message(msg_id, &other).await.unwrap());
+    assert_eq!(retry_state(&storage, msg_id).await.1, 1);
+
+    queue.complete_message(msg_id).await.unwrap();
+}
@@ -0,0 +1,61 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use std::sync::Arc;
+
+use opencode_mem_core::{Observation, ObservationType};
+use opencode_mem_embeddings::error::EmbeddingError;
+use opencode_mem_embeddings::{LazyEmbeddingService, LoadState};
+
+use super::*;
+use crate::test_support::pg_setup;
+
+/// An embedding service whose model never loads, so every embed call fails.
+fn broken_embeddings() -> Arc<LazyEmbeddingService> {
+    Arc::new(LazyEmbeddingService::with_loader(|| {
+        Err(EmbeddingError::ModelInit("model unavailable".to_owned()))
+    }))
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_failed_embedding_still_returns_fts_results() {
+    let (storage, _) = pg_setup().await;
+    let marker = format!("fallbackmarker{}", uuid::Uuid::new_v4().simple());
+    let project = format!("fallback-test-{}", uuid::Uuid::new_v4());
+    let obs = Observation::builder(
+        uuid::Uuid::new_v4().to_string(),
+        "fallback-test".to_owned(),
+        ObservationType::Discovery,
+        format!("Embedding outage keeps {marker} searchable"),
+    )
+    .project(project.as_str())
+    .build();
+    storage.save_observation(&obs).await.unwrap();
+
+    let service = SearchService::new(Arc::clone(&storage), Some(broken_embeddings()), None, 0.9);
+    let found = |results: &[SearchResult]| results.iter().any(|r| r.id == obs.id);
+
+    // Twice: the second call must not be wedged by whatever the first left behind.
+    for _ in 0..2 {
+        let results = service.hybrid_search(&marker, 10).await.unwrap();
+        assert!(found(&results), "hybrid search must fall back to FTS");
+    }
+    let results = service
+        .smart_search(Some(&marker), Some(&project), None, None, None, 10)
+        .await
+        .unwrap();
+    assert!(found(&results), "filtered search must fall back to FTS");
+    let results = service
+        .semantic_search_with_fallback(&marker, 10)
+        .await
+        .unwrap();
+    assert!(found(&results), "semantic search must fall back to FTS");
+    assert_eq!(service.embeddings_state(), Some(LoadState::Pending));
+
+    sqlx::query("DELETE FROM observations WHERE id = $1")
+        .bind(obs.id.as_ref())
+        .execute(&storage.pool())
+        .await
+        .unwrap();
+}
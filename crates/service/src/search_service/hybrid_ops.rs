@@ -4,11 +4,13 @@
 //! (embed query → choose hybrid_search_v2 or fallback) now lives directly
 //! in `SearchService`, eliminating the `anyhow::Result` type-erasure layer.
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use opencode_mem_core::SearchResult;
+use opencode_mem_core::{Observation, ObservationSort, SearchResult, normalize_query};
 use opencode_mem_embeddings::{EmbeddingProvider, LazyEmbeddingService};
-use opencode_mem_storage::traits::SearchStore;
+use opencode_mem_storage::traits::{ObservationStore, SearchStore};
 
 use crate::ServiceError;
 
@@ -20,16 +22,21 @@ impl SearchService {
     /// When embeddings are available, generates query embedding and uses
     /// `hybrid_search_v2` (50% FTS BM25 + 50% vector cosine similarity).
     /// Otherwise falls back to text-only `hybrid_search` (70% FTS + 30% keyword overlap).
+    /// Blank queries return an empty list (see [`normalize_query`]).
     pub async fn hybrid_search(
         &self,
         query: &str,
         limit: usize,
     ) -> Result<Vec<SearchResult>, ServiceError> {
+        let Some(query) = normalize_query(query) else {
+            return Ok(Vec::new());
+        };
         let limit = Self::normalize_limit(limit);
         self.run_hybrid_search(query, limit).await
     }
 
     /// Search with additional filters (project, observation type, date range).
+    /// A blank query is treated as absent: results are listed by recency.
     pub async fn search_with_filters(
         &self,
         query: Option<&str>,
@@ -40,6 +47,7 @@ impl SearchService {
         limit: usize,
     ) -> Result<Vec<SearchResult>, ServiceError> {
         let limit = Self::normalize_limit(limit);
+        let query = query.and_then(normalize_query);
         let obs_type_lower = obs_type.map(|t| t.to_lowercase());
         let obs_type_ref = obs_type_lower.as_deref();
         self.run_search_with_filters(query, project, obs_type_ref, from, to, limit)
@@ -63,7 +71,7 @@ impl SearchService {
     ) -> Result<Vec<SearchResult>, ServiceError> {
         let limit = Self::normalize_limit(limit);
         let has_filters = project.is_some() || obs_type.is_some() || from.is_some() || to.is_some();
-        let query_normalized = query.filter(|s| !s.is_empty());
+        let query_normalized = query.and_then(normalize_query);
 
         if !has_filters && let Some(q) = query_normalized {
             return self.hybrid_search(q, limit).await;
@@ -74,19 +82,109 @@ impl SearchService {
             .await
     }
 
+    /// Search and hydrate the top results into full observations in one call.
+    ///
+    /// Runs `smart_search`, then fetches the matching observations by ID and
+    /// returns them in search-rank order. Convenience path for clients that
+    /// don't need the two-phase search → `get_observations` pattern.
+    pub async fn search_full(
+        &self,
+        query: &str,
+        project: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Observation>, ServiceError> {
+        let Some(query) = normalize_query(query) else {
+            return Ok(Vec::new());
+        };
+        let results = self
+            .smart_search(Some(query), project, None, None, None, limit)
+            .await?;
+        if results.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<String> = results.iter().map(|r| r.id.to_string()).collect();
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_observations_by_ids(&ids))
+            .await;
+        let mut by_id: HashMap<String, Observation> = self
+            .with_cb(result)?
+            .into_iter()
+            .map(|o| (o.id.to_string(), o))
+            .collect();
+
+        Ok(ids.iter().filter_map(|id| by_id.remove(id)).collect())
+    }
+
+    /// Reorder ranked search results. `Score` keeps the rank order; the other
+    /// sorts look up creation times for the returned observations and, like the
+    /// SQL listings, break ties on `created_at` (newest first) and then id.
+    pub async fn sort_results(
+        &self,
+        mut results: Vec<SearchResult>,
+        sort: ObservationSort,
+    ) -> Result<Vec<SearchResult>, ServiceError> {
+        if sort == ObservationSort::Score || results.len() < 2 {
+            return Ok(results);
+        }
+        let ids: Vec<String> = results.iter().map(|r| r.id.to_string()).collect();
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_observations_by_ids(&ids))
+            .await;
+        let created: HashMap<String, _> = self
+            .with_cb(result)?
+            .into_iter()
+            .map(|o| (o.id.to_string(), o.created_at))
+            .collect();
+        results.sort_by(|a, b| {
+            let (ta, tb) = (created.get(a.id.as_ref()), created.get(b.id.as_ref()));
+            let primary = match sort {
+                ObservationSort::Type => {
+                    a.observation_type.as_str().cmp(b.observation_type.as_str())
+                }
+                ObservationSort::Noise => a.noise_level.cmp(&b.noise_level),
+                _ => Ordering::Equal,
+            };
+            let by_time = if sort == ObservationSort::CreatedAsc {
+                ta.cmp(&tb)
+            } else {
+                tb.cmp(&ta)
+            };
+            primary.then(by_time).then_with(|| a.id.0.cmp(&b.id.0))
+        });
+        Ok(results)
+    }
+
     /// Semantic search with automatic 3-tier fallback:
     /// 1. Vector search via embeddings
     /// 2. If vector results are empty → hybrid search
     /// 3. If embedding fails or unavailable → hybrid search
+    ///
+    /// Blank queries return an empty list.
     pub async fn semantic_search_with_fallback(
         &self,
         query: &str,
         limit: usize,
     ) -> Result<Vec<SearchResult>, ServiceError> {
+        let Some(query) = normalize_query(query) else {
+            return Ok(Vec::new());
+        };
         let limit = Self::normalize_limit(limit);
         self.run_semantic_search_with_fallback(query, limit).await
     }
 
+    /// Embed a one-word probe to check the embedding model responds.
+    pub async fn probe_embeddings(&self) -> Result<(), ServiceError> {
+        let Some(ref emb) = self.embeddings else {
+            return Err(ServiceError::NotConfigured(
+                "embeddings disabled".to_owned(),
+            ));
+        };
+        embed_query(emb, "ping").await.map(|_| ())
+    }
+
     // ── Private routing implementations ─────────────────────────────────
 
     async fn run_hybrid_search(
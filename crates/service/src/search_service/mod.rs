@@ -1,16 +1,23 @@
 //! Search service — read-only query facade over storage and embeddings.
 
 mod embedding_ops;
+#[cfg(test)]
+mod fallback_tests;
 mod hybrid_ops;
 mod query_ops;
+#[cfg(test)]
+mod sort_tests;
 
 use std::sync::Arc;
 
-use opencode_mem_core::{Observation, SearchResult, cap_query_limit};
-use opencode_mem_embeddings::LazyEmbeddingService;
+use opencode_mem_core::{
+    Concept, Observation, ObservationSort, SearchResult, cap_query_limit, normalize_query,
+};
+use opencode_mem_embeddings::{LazyEmbeddingService, LoadState};
 use opencode_mem_storage::traits::{ObservationStore, SearchStore, StatsStore};
 use opencode_mem_storage::{
-    CircuitBreaker, PaginatedResult, StorageBackend, StorageError, StorageStats,
+    CircuitBreaker, PaginatedResult, ProjectFileActivity, StorageBackend, StorageError,
+    StorageStats,
 };
 
 use crate::InfiniteMemoryService;
@@ -43,6 +50,17 @@ impl SearchService {
         self.storage.circuit_breaker()
     }
 
+    /// Storage liveness probe for health checks.
+    pub async fn ping_storage(&self) -> Result<(), ServiceError> {
+        let result = self.storage.guarded(|| self.storage.ping()).await;
+        self.with_cb(result)
+    }
+
+    /// `None` when embeddings are disabled, otherwise the model's load state.
+    pub fn embeddings_state(&self) -> Option<LoadState> {
+        self.embeddings.as_ref().map(|emb| emb.load_state())
+    }
+
     pub(crate) fn normalize_limit(limit: usize) -> usize {
         cap_query_limit(limit)
     }
@@ -86,15 +104,26 @@ impl SearchService {
         self.with_cb(result)
     }
 
+    /// Most recent observations, optionally scoped to `project`
+    /// (`None` returns recent observations across all projects).
     pub async fn get_recent_observations(
         &self,
+        project: Option<&str>,
         limit: usize,
     ) -> Result<Vec<Observation>, ServiceError> {
         let limit = Self::normalize_limit(limit);
-        let result = self
-            .storage
-            .guarded(|| self.storage.get_recent(limit))
-            .await;
+        let result = match project {
+            Some(project) => {
+                self.storage
+                    .guarded(|| self.storage.get_recent_for_project(project, limit))
+                    .await
+            }
+            None => {
+                self.storage
+                    .guarded(|| self.storage.get_recent(limit))
+                    .await
+            }
+        };
         self.with_cb(result)
     }
 
@@ -136,6 +165,38 @@ impl SearchService {
         self.with_cb(result)
     }
 
+    /// Exact keyword lookup over the structured `keywords` field.
+    /// Blank keywords return an empty list (see [`normalize_query`]).
+    pub async fn search_by_keyword(
+        &self,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, ServiceError> {
+        let Some(keyword) = normalize_query(keyword) else {
+            return Ok(Vec::new());
+        };
+        let limit = Self::normalize_limit(limit);
+        let result = self
+            .storage
+            .guarded(|| self.storage.search_by_keyword(keyword, limit))
+            .await;
+        self.with_cb(result)
+    }
+
+    pub async fn search_by_concept(
+        &self,
+        concept: Concept,
+        project: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, ServiceError> {
+        let limit = Self::normalize_limit(limit);
+        let result = self
+            .storage
+            .guarded(|| self.storage.search_by_concept(concept, project, limit))
+            .await;
+        self.with_cb(result)
+    }
+
     pub async fn get_stats(&self) -> Result<StorageStats, ServiceError> {
         let result = self.storage.guarded(|| self.storage.get_stats()).await;
         self.with_cb(result)
@@ -149,18 +210,51 @@ impl SearchService {
         self.with_cb(result)
     }
 
+    pub async fn get_project_files(
+        &self,
+        project: &str,
+        limit: usize,
+    ) -> Result<Vec<ProjectFileActivity>, ServiceError> {
+        let limit = Self::normalize_limit(limit);
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_project_files(project, limit))
+            .await;
+        self.with_cb(result)
+    }
+
+    pub async fn count_observations(
+        &self,
+        project: Option<&str>,
+        obs_type: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<usize, ServiceError> {
+        let obs_type_lower = obs_type.map(|t| t.to_lowercase());
+        let obs_type_ref = obs_type_lower.as_deref();
+        let result = self
+            .storage
+            .guarded(|| {
+                self.storage
+                    .count_observations(project, obs_type_ref, from, to)
+            })
+            .await;
+        self.with_cb(result)
+    }
+
     pub async fn get_observations_paginated(
         &self,
         offset: usize,
         limit: usize,
         project: Option<&str>,
+        sort: ObservationSort,
     ) -> Result<PaginatedResult<Observation>, ServiceError> {
         let limit = Self::normalize_limit(limit);
         let result = self
             .storage
             .guarded(|| {
                 self.storage
-                    .get_observations_paginated(offset, limit, project)
+                    .get_observations_paginated(offset, limit, project, sort)
             })
             .await;
         self.with_cb(result)
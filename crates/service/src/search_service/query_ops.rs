@@ -1,7 +1,7 @@
 //! Knowledge, session, and prompt query methods for SearchService.
 
 use opencode_mem_core::{
-    GlobalKnowledge, KnowledgeSearchResult, KnowledgeType, SessionSummary, UserPrompt,
+    GlobalKnowledge, KnowledgeSearchResult, KnowledgeType, Observation, SessionSummary, UserPrompt,
 };
 use opencode_mem_storage::{
     PaginatedResult,
@@ -72,6 +72,18 @@ impl SearchService {
         self.with_cb(result)
     }
 
+    /// Observations produced while the given prompt was the session's latest.
+    pub async fn get_prompt_observations(
+        &self,
+        prompt_id: &str,
+    ) -> Result<Vec<Observation>, ServiceError> {
+        let result = self
+            .storage
+            .guarded(|| self.storage.get_prompt_observations(prompt_id))
+            .await;
+        self.with_cb(result)
+    }
+
     pub async fn get_prompts_paginated(
         &self,
         offset: usize,
@@ -0,0 +1,53 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use chrono::{Duration, Utc};
+use opencode_mem_core::{NoiseLevel, ObservationType};
+
+use super::*;
+use crate::test_support::pg_setup;
+
+#[tokio::test]
+#[ignore]
+async fn test_type_and_noise_sorts_break_ties_newest_first() {
+    let (storage, _) = pg_setup().await;
+    let now = Utc::now();
+    let mut observations = Vec::new();
+    // Id order differs from age order, so an id-only tie-break fails the assertion.
+    for (i, age_hours) in [3, 1, 2].into_iter().enumerate() {
+        let obs = Observation::builder(
+            format!("sort-{i}-{}", uuid::Uuid::new_v4()),
+            "sort-test".to_owned(),
+            ObservationType::Discovery,
+            format!("Sort tie-break {}", uuid::Uuid::new_v4()),
+        )
+        .noise_level(NoiseLevel::High)
+        .created_at(now - Duration::hours(age_hours))
+        .build();
+        storage.save_observation(&obs).await.unwrap();
+        observations.push(obs);
+    }
+    let results: Vec<SearchResult> = observations
+        .iter()
+        .map(SearchResult::from_observation)
+        .collect();
+    let newest_first = [
+        &observations[1].id,
+        &observations[2].id,
+        &observations[0].id,
+    ];
+
+    let service = SearchService::new(Arc::clone(&storage), None, None, 0.9);
+    for sort in [ObservationSort::Type, ObservationSort::Noise] {
+        let sorted = service.sort_results(results.clone(), sort).await.unwrap();
+        let ids: Vec<_> = sorted.iter().map(|r| &r.id).collect();
+        assert_eq!(ids, newest_first, "{sort:?}");
+    }
+
+    let ids: Vec<&str> = observations.iter().map(|o| o.id.as_ref()).collect();
+    sqlx::query("DELETE FROM observations WHERE id = ANY($1)")
+        .bind(&ids)
+        .execute(&storage.pool())
+        .await
+        .unwrap();
+}
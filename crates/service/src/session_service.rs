@@ -1,11 +1,15 @@
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use chrono::{TimeDelta, Utc};
 use opencode_mem_core::{
-    Observation, ProjectId, Session, SessionId, SessionStatus, SessionSummary,
+    Observation, ProjectId, PromptNumber, Session, SessionId, SessionStatus, SessionSummary,
+    UserPrompt,
 };
 use opencode_mem_llm::LlmClient;
-use opencode_mem_storage::traits::{ObservationStore, SessionStore, SummaryStore};
+use opencode_mem_storage::traits::{
+    ObservationStore, PendingQueueStore, PromptStore, SessionStore, SummaryStore,
+};
 use opencode_mem_storage::{StorageBackend, StorageError};
 
 use crate::ServiceError;
@@ -17,6 +21,9 @@ const MAX_OBSERVATIONS_FOR_SUMMARY: usize = 150;
 /// Stale "processing" placeholder threshold.
 const STALE_PLACEHOLDER_MINUTES: i64 = 10;
 
+/// Poll interval while waiting for a session's queued observations to land.
+const COMPLETION_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
 /// Truncate observations to the last [`MAX_OBSERVATIONS_FOR_SUMMARY`] items.
 /// Returns a slice of the most recent observations.
 fn truncate_observations_for_summary(observations: &[Observation]) -> &[Observation] {
@@ -30,15 +37,38 @@ fn truncate_observations_for_summary(observations: &[Observation]) -> &[Observat
     }
 }
 
+#[derive(Clone)]
 pub struct SessionService {
     storage: Arc<StorageBackend>,
     llm: Arc<LlmClient>,
+    completion_grace: Duration,
 }
 
 impl SessionService {
     #[must_use]
     pub const fn new(storage: Arc<StorageBackend>, llm: Arc<LlmClient>) -> Self {
-        Self { storage, llm }
+        Self {
+            storage,
+            llm,
+            completion_grace: Duration::ZERO,
+        }
+    }
+
+    /// Sets how long `complete_session` waits for the session's queued
+    /// observations to finish processing before summarizing.
+    #[must_use]
+    pub const fn with_completion_grace(mut self, grace: Duration) -> Self {
+        self.completion_grace = grace;
+        self
+    }
+
+    /// Returns a copy of this service whose LLM calls use `model` instead of
+    /// the configured default. Used for per-request model overrides.
+    #[must_use]
+    pub fn with_llm_model(&self, model: String) -> Self {
+        let mut svc = self.clone();
+        svc.llm = Arc::new(self.llm.as_ref().clone().with_model(model));
+        svc
     }
 
     pub fn circuit_breaker(&self) -> &opencode_mem_storage::CircuitBreaker {
@@ -49,15 +79,76 @@ impl SessionService {
         result.map_err(ServiceError::from)
     }
 
-    pub async fn init_session(&self, session: Session) -> Result<Session, ServiceError> {
+    /// Persist `session` and record its prompt.
+    ///
+    /// `prompt_number` is the caller's position for `session.user_prompt`,
+    /// when it tracks one. Resubmitting a prompt at a known position (e.g.
+    /// after a plugin reconnect) is then a no-op.
+    pub async fn init_session(
+        &self,
+        session: Session,
+        prompt_number: Option<u32>,
+    ) -> Result<Session, ServiceError> {
         let result = self
             .storage
             .guarded(|| self.storage.save_session(&session))
             .await;
         self.with_cb(result)?;
+        if let Some(text) = session.user_prompt.as_deref()
+            && !text.trim().is_empty()
+        {
+            self.record_prompt(&session, text, prompt_number.filter(|&n| n > 0))
+                .await?;
+        }
         Ok(session)
     }
 
+    /// Store the prompt under its number, so observations stamped with it can
+    /// be traced back to the prompt.
+    ///
+    /// Numbers are per content session, which outlives the session rows
+    /// created for each of its prompts. A caller-supplied `prompt_number` is
+    /// used as is; a second prompt at the same position hits the
+    /// `(content_session_id, prompt_number)` unique index and is dropped.
+    /// Without one the prompt takes the next number, so repeating the same
+    /// text ("continue", "yes") records a new prompt.
+    async fn record_prompt(
+        &self,
+        session: &Session,
+        text: &str,
+        prompt_number: Option<u32>,
+    ) -> Result<(), ServiceError> {
+        // `save_next_user_prompt` assigns the number itself.
+        let prompt = UserPrompt::new(
+            uuid::Uuid::new_v4().to_string(),
+            session.content_session_id.clone(),
+            PromptNumber(prompt_number.unwrap_or_default()),
+            text.to_owned(),
+            Some(session.project.clone()),
+            Utc::now(),
+        );
+        let Some(n) = prompt_number else {
+            let result = self
+                .storage
+                .guarded(|| self.storage.save_next_user_prompt(&prompt))
+                .await;
+            self.with_cb(result)?;
+            return Ok(());
+        };
+        let result = self
+            .storage
+            .guarded(|| self.storage.save_user_prompt(&prompt))
+            .await;
+        if !self.with_cb(result)? {
+            tracing::debug!(
+                session_id = %session.id,
+                prompt_number = n,
+                "Prompt already recorded at this position"
+            );
+        }
+        Ok(())
+    }
+
     pub async fn get_session(&self, id: &str) -> Result<Option<Session>, ServiceError> {
         let result = self.storage.guarded(|| self.storage.get_session(id)).await;
         self.with_cb(result)
@@ -101,7 +192,52 @@ impl SessionService {
         self.with_cb(result)
     }
 
+    /// Wait (bounded by the completion grace period) until the session has no
+    /// pending or processing queue messages left.
+    ///
+    /// Returns `true` if the queue drained, `false` on timeout. Errors while
+    /// polling end the wait early — summarizing with what exists is preferable
+    /// to failing the completion.
+    pub(crate) async fn wait_for_in_flight(&self, session_id: &str) -> bool {
+        if self.completion_grace.is_zero() {
+            return true;
+        }
+        let deadline = Instant::now()
+            .checked_add(self.completion_grace)
+            .unwrap_or_else(Instant::now);
+        loop {
+            let result = self
+                .storage
+                .guarded(|| self.storage.get_session_in_flight_count(session_id))
+                .await;
+            match self.with_cb(result) {
+                Ok(0) => return true,
+                Ok(in_flight) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        tracing::warn!(
+                            session_id,
+                            in_flight,
+                            grace_secs = self.completion_grace.as_secs(),
+                            "Grace period elapsed with observations still in flight, summarizing anyway"
+                        );
+                        return false;
+                    }
+                    tokio::time::sleep(
+                        COMPLETION_POLL_INTERVAL.min(deadline.saturating_duration_since(now)),
+                    )
+                    .await;
+                }
+                Err(e) => {
+                    tracing::warn!(session_id, error = %e, "Failed to poll in-flight observations");
+                    return false;
+                }
+            }
+        }
+    }
+
     pub async fn complete_session(&self, session_id: &str) -> Result<Option<String>, ServiceError> {
+        self.wait_for_in_flight(session_id).await;
         let observations = self
             .storage
             .guarded(|| self.storage.get_session_observations(session_id))
@@ -450,3 +586,7 @@ impl SessionService {
         Ok(generated)
     }
 }
+
+#[cfg(test)]
+#[path = "session_service_tests.rs"]
+mod tests;
@@ -0,0 +1,191 @@
+// These tests require a running PostgreSQL instance.
+// Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use super::*;
+use crate::test_support::{mock_llm, pg_setup};
+use opencode_mem_core::ObservationType;
+
+/// A session service whose LLM is `base_url`; pass an empty URL when the
+/// test never reaches the LLM.
+fn session_service(storage: &Arc<StorageBackend>, base_url: String) -> SessionService {
+    let llm = LlmClient::new("test-key".to_owned(), base_url, "test-model".to_owned()).unwrap();
+    SessionService::new(Arc::clone(storage), Arc::new(llm))
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_in_flight_observation_included_after_grace() {
+    let (storage, _) = pg_setup().await;
+    let session_id = format!("grace-test-{}", uuid::Uuid::new_v4());
+
+    let msg_id = storage
+        .queue_message(
+            &session_id,
+            None,
+            Some("bash"),
+            Some("{}"),
+            Some("ok"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let server = mock_llm(vec![
+        serde_json::json!({ "summary": "Worked through the late observation." }).to_string(),
+    ])
+    .await;
+    let service =
+        session_service(&storage, server.uri()).with_completion_grace(Duration::from_secs(5));
+
+    // Simulates the queue worker finishing the observation shortly after
+    // `session_complete` has been called.
+    let worker_storage = Arc::clone(&storage);
+    let worker_session = session_id.clone();
+    let late_title = format!("Late observation {}", uuid::Uuid::new_v4());
+    let worker_title = late_title.clone();
+    let worker = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let obs = Observation::builder(
+            uuid::Uuid::new_v4().to_string(),
+            worker_session,
+            ObservationType::Discovery,
+            worker_title,
+        )
+        .build();
+        worker_storage.save_observation(&obs).await.unwrap();
+        worker_storage.complete_message(msg_id).await.unwrap();
+    });
+
+    let summary = service.complete_session(&session_id).await.unwrap();
+    worker.await.unwrap();
+
+    assert_eq!(
+        summary.as_deref(),
+        Some("Worked through the late observation.")
+    );
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(
+        String::from_utf8_lossy(&requests[0].body).contains(&late_title),
+        "In-flight observation should be included in the summary input"
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_wait_for_in_flight_times_out() {
+    let (storage, _) = pg_setup().await;
+    let session_id = format!("grace-timeout-{}", uuid::Uuid::new_v4());
+
+    let msg_id = storage
+        .queue_message(
+            &session_id,
+            None,
+            Some("bash"),
+            Some("{}"),
+            Some("ok"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let service =
+        session_service(&storage, String::new()).with_completion_grace(Duration::from_millis(300));
+
+    let started = Instant::now();
+    let drained = service.wait_for_in_flight(&session_id).await;
+
+    assert!(!drained, "Stuck message should exhaust the grace period");
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    storage.complete_message(msg_id).await.unwrap();
+}
+
+fn prompt_session(id: &str, prompt: &str) -> Session {
+    content_session_prompt(id, id, prompt)
+}
+
+fn content_session_prompt(id: &str, content_session_id: &str, prompt: &str) -> Session {
+    Session::new(
+        opencode_mem_core::SessionId(id.to_owned()),
+        opencode_mem_core::ContentSessionId(content_session_id.to_owned()),
+        None,
+        opencode_mem_core::ProjectId::new("prompt-test".to_owned()),
+        Some(prompt.to_owned()),
+        Utc::now(),
+        None,
+        opencode_mem_core::SessionStatus::Active,
+        0,
+    )
+}
+
+async fn prompt_numbers(storage: &StorageBackend, content_session_id: &str) -> Vec<i32> {
+    sqlx::query_scalar(
+        "SELECT prompt_number FROM user_prompts WHERE content_session_id = $1 ORDER BY prompt_number",
+    )
+    .bind(content_session_id)
+    .fetch_all(&storage.pool())
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_repeated_prompt_text_is_recorded_each_time() {
+    let (storage, _) = pg_setup().await;
+    let service = session_service(&storage, String::new());
+    let id = format!("prompt-repeat-{}", uuid::Uuid::new_v4());
+
+    for _ in 0..2 {
+        service
+            .init_session(prompt_session(&id, "continue"), None)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(prompt_numbers(&storage, &id).await, vec![1, 2]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_resubmitted_prompt_at_same_position_is_noop() {
+    let (storage, _) = pg_setup().await;
+    let service = session_service(&storage, String::new());
+    let id = format!("prompt-reconnect-{}", uuid::Uuid::new_v4());
+
+    for _ in 0..2 {
+        service
+            .init_session(prompt_session(&id, "Refactor the queue"), Some(1))
+            .await
+            .unwrap();
+    }
+    service
+        .init_session(prompt_session(&id, "continue"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(prompt_numbers(&storage, &id).await, vec![1, 2]);
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_prompts_numbered_per_content_session_across_session_rows() {
+    let (storage, _) = pg_setup().await;
+    let service = session_service(&storage, String::new());
+    let content_id = format!("prompt-content-{}", uuid::Uuid::new_v4());
+
+    // Hook clients create a fresh session row for every prompt.
+    for (number, prompt) in (1..).zip(["Refactor the queue", "continue"]) {
+        let id = uuid::Uuid::new_v4().to_string();
+        service
+            .init_session(content_session_prompt(&id, &content_id, prompt), None)
+            .await
+            .unwrap();
+        let current = storage.get_current_prompt_number(&id).await.unwrap();
+        assert_eq!(current, Some(number));
+    }
+
+    assert_eq!(prompt_numbers(&storage, &content_id).await, vec![1, 2]);
+}
@@ -0,0 +1,98 @@
+//! Shared setup for service tests that need a running PostgreSQL instance.
+//! Run with: DATABASE_URL=... cargo test -p opencode-mem-service -- --ignored
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use opencode_mem_core::AppConfig;
+use opencode_mem_storage::StorageBackend;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+/// Connects to `DATABASE_URL` and returns the config services are built from:
+/// the `AppConfig::from_env` defaults, with no LLM endpoint or embeddings.
+pub(crate) async fn pg_setup() -> (Arc<StorageBackend>, AppConfig) {
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set for tests");
+    let storage = Arc::new(
+        StorageBackend::new(&database_url)
+            .await
+            .expect("Failed to connect to PG"),
+    );
+    let config = AppConfig {
+        database_url,
+        api_key: String::new(),
+        api_url: String::new(),
+        model: String::new(),
+        disable_embeddings: true,
+        embedding_threads: 0,
+        reembed_on_dimension_change: false,
+        infinite_memory_url: None,
+        llm_breaker_threshold: 5,
+        llm_breaker_window_secs: 60,
+        llm_breaker_cooldown_secs: 30,
+        dedup_threshold: 0.85,
+        injection_dedup_threshold: 0.80,
+        queue_workers: 10,
+        max_retry: 3,
+        visibility_timeout_secs: 300,
+        dlq_ttl_days: 7,
+        session_complete_grace_secs: 0,
+        require_narrative: false,
+        strip_ansi: true,
+        prompt_injection_guard: true,
+        summary_language: opencode_mem_core::DEFAULT_SUMMARY_LANGUAGE.to_owned(),
+        max_candidates: 0,
+        max_candidate_chars: 0,
+        merge_require_confirm: false,
+        transient_ttl_hours: 0,
+        max_content_chars: 500,
+        max_total_chars: 8000,
+        max_events: 200,
+        infinite_batch_window_ms: 0,
+        infinite_batch_max_events: 100,
+        max_result_limit: opencode_mem_core::DEFAULT_MAX_RESULT_LIMIT,
+        admin_token: None,
+        excluded_projects_raw: None,
+        filter_patterns_raw: None,
+        allowed_models: Vec::new(),
+    };
+    (storage, config)
+}
+
+/// Answers successive chat completions with `contents` in order, repeating
+/// the last one.
+struct ChatReplies {
+    contents: Vec<String>,
+    served: AtomicUsize,
+}
+
+impl Respond for ChatReplies {
+    fn respond(&self, _request: &Request) -> ResponseTemplate {
+        let n = self.served.fetch_add(1, Ordering::SeqCst);
+        let content = self
+            .contents
+            .get(n)
+            .or(self.contents.last())
+            .cloned()
+            .unwrap_or_default();
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "choices": [{ "message": { "role": "assistant", "content": content } }]
+        }))
+    }
+}
+
+/// OpenAI-compatible endpoint replying with `contents` in order. Pass
+/// `server.uri()` to `LlmClient::new`; `server.received_requests()` counts
+/// the completions served.
+pub(crate) async fn mock_llm(contents: Vec<String>) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .respond_with(ChatReplies {
+            contents,
+            served: AtomicUsize::new(0),
+        })
+        .mount(&server)
+        .await;
+    server
+}
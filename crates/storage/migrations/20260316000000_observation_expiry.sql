@@ -0,0 +1,5 @@
+-- Optional per-observation TTL. NULL means the observation never expires.
+ALTER TABLE observations ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_obs_expires_at ON observations (expires_at)
+    WHERE expires_at IS NOT NULL;
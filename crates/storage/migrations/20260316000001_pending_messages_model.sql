@@ -0,0 +1,2 @@
+-- Per-request LLM model override carried from the enqueueing request to the queue worker.
+ALTER TABLE pending_messages ADD COLUMN IF NOT EXISTS model TEXT;
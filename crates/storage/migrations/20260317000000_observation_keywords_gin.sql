@@ -0,0 +1,2 @@
+-- Exact keyword lookup (`keywords @> '["term"]'`) for /api/search/by-keyword.
+CREATE INDEX IF NOT EXISTS idx_obs_keywords ON observations USING GIN (keywords jsonb_path_ops);
@@ -0,0 +1,8 @@
+-- Hash of (tool, normalized input, normalized output) for the tool call that
+-- produced an observation. Lets identical retries within a session be
+-- skipped before the LLM, regardless of how the LLM would word the result.
+ALTER TABLE observations ADD COLUMN IF NOT EXISTS tool_call_hash TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_obs_session_tool_call_hash
+    ON observations (session_id, tool_call_hash)
+    WHERE tool_call_hash IS NOT NULL;
@@ -0,0 +1,2 @@
+-- Concept containment (`concepts @> '["gotcha"]'`) for /api/search/by-concept.
+CREATE INDEX IF NOT EXISTS idx_obs_concepts ON observations USING GIN (concepts jsonb_path_ops);
@@ -0,0 +1,10 @@
+-- A plugin reconnect can resubmit the same prompt; keep one row per
+-- (session, prompt number) so counts and prompt linking stay accurate.
+DELETE FROM user_prompts a
+    USING user_prompts b
+    WHERE a.content_session_id = b.content_session_id
+      AND a.prompt_number = b.prompt_number
+      AND (a.created_at, a.id) > (b.created_at, b.id);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_up_session_prompt
+    ON user_prompts (content_session_id, prompt_number);
@@ -33,6 +33,17 @@ pub enum StorageError {
     #[error("migration error: {0}")]
     Migration(String),
 
+    /// A pgvector column is sized for a different model than the configured one.
+    /// Existing vectors must be regenerated before the new model can be used.
+    #[error(
+        "embedding dimension mismatch: {table}.embedding is vector({found}) but the model produces {expected} dimensions; re-embedding required"
+    )]
+    EmbeddingDimensionMismatch {
+        table: &'static str,
+        expected: usize,
+        found: usize,
+    },
+
     /// Database is unavailable (circuit breaker open).
     /// Callers should return empty results for reads, skip for writes.
     #[error("database unavailable (circuit breaker open, next probe in {seconds_until_probe}s)")]
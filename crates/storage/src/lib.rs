@@ -41,8 +41,8 @@ pub use backend::StorageBackend;
 pub use circuit_breaker::CircuitBreaker;
 pub use error::StorageError;
 pub use pending_queue::{
-    PaginatedResult, PendingMessage, PendingMessageStatus, QueueStats, StorageStats,
-    default_visibility_timeout_secs, init_queue_config, max_retry_count,
+    PaginatedResult, PendingMessage, PendingMessageStatus, ProjectFileActivity, QueueStats,
+    StorageStats, default_visibility_timeout_secs, init_queue_config, max_retry_count,
 };
 pub use pg_storage::PgStorage;
 pub use traits::{
@@ -1,5 +1,6 @@
 //! Storage types shared across modules
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -19,6 +20,38 @@ pub struct StorageStats {
     pub project_count: u64,
 }
 
+/// How often a file appears in a project's observations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProjectFileActivity {
+    /// File path as recorded on the observations.
+    pub path: String,
+    /// Number of observations listing the file in `files_modified`.
+    pub modified_count: u64,
+    /// Number of observations listing the file in `files_read`.
+    pub read_count: u64,
+    /// Creation time of the newest observation touching the file.
+    pub last_touched: DateTime<Utc>,
+}
+
+impl ProjectFileActivity {
+    /// Construct from SQL aggregates (`COUNT(*)` returns `i64`).
+    #[must_use]
+    pub fn new(
+        path: String,
+        modified_count: i64,
+        read_count: i64,
+        last_touched: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            path,
+            modified_count: u64::try_from(modified_count).unwrap_or(0),
+            read_count: u64::try_from(read_count).unwrap_or(0),
+            last_touched,
+        }
+    }
+}
+
 /// Generic paginated result
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[non_exhaustive]
@@ -130,6 +163,9 @@ pub struct PendingMessage {
     pub completed_at_epoch: Option<i64>,
     /// Project this message belongs to.
     pub project: Option<String>,
+    /// LLM model override requested by the caller that enqueued this message.
+    #[serde(default)]
+    pub model: Option<String>,
 }
 
 impl PendingMessage {
@@ -156,8 +192,16 @@ impl PendingMessage {
             claimed_at_epoch: None,
             completed_at_epoch: None,
             project,
+            model: None,
         }
     }
+
+    /// Sets the LLM model override used when this message is processed.
+    #[must_use]
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
 }
 
 use std::sync::OnceLock;
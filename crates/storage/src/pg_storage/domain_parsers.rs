@@ -158,6 +158,7 @@ pub(crate) fn row_to_pending_message(
         claimed_at_epoch: row.try_get("claimed_at_epoch")?,
         completed_at_epoch: row.try_get("completed_at_epoch")?,
         project: row.try_get("project")?,
+        model: row.try_get("model")?,
     })
 }
 
@@ -41,6 +41,32 @@ pub async fn store_infinite_event(
     Ok(row.0)
 }
 
+/// Insert many events in one multi-row statement. Events whose `call_id` is
+/// already stored are skipped. Returns the number of rows inserted.
+pub async fn store_infinite_events(
+    pool: &PgPool,
+    events: &[RawInfiniteEvent],
+) -> Result<u64, StorageError> {
+    if events.is_empty() {
+        return Ok(0);
+    }
+    let mut builder = sqlx::QueryBuilder::new(
+        "INSERT INTO raw_events (session_id, project, event_type, content, files, tools, call_id) ",
+    );
+    builder.push_values(events, |mut row, event| {
+        row.push_bind(&event.session_id)
+            .push_bind(&event.project)
+            .push_bind(event.event_type.as_str())
+            .push_bind(&event.content)
+            .push_bind(&event.files)
+            .push_bind(&event.tools)
+            .push_bind(&event.call_id);
+    });
+    builder.push(" ON CONFLICT (call_id) WHERE call_id IS NOT NULL DO NOTHING");
+    let result = builder.build().execute(pool).await?;
+    Ok(result.rows_affected())
+}
+
 pub async fn get_recent_infinite_events(
     pool: &PgPool,
     limit: i64,
@@ -0,0 +1,56 @@
+use crate::StorageError;
+use sqlx::pool::PoolConnection;
+use sqlx::{PgPool, Postgres};
+
+/// Advisory lock key serializing compression runs across processes
+/// (ASCII "ocm_comp").
+const COMPRESSION_LOCK_KEY: i64 = 0x6f63_6d5f_636f_6d70;
+
+/// Session-level advisory lock held for the duration of a compression run.
+///
+/// The lock lives on a dedicated pool connection. Call [`release`](Self::release)
+/// when done; if the guard is dropped instead, the connection is detached and
+/// closed so the lock can never leak back into the pool.
+pub struct CompressionLock {
+    conn: Option<PoolConnection<Postgres>>,
+}
+
+/// Try to take the compression lock without waiting.
+/// Returns `None` when another runner already holds it.
+pub async fn try_acquire_compression_lock(
+    pool: &PgPool,
+) -> Result<Option<CompressionLock>, StorageError> {
+    let mut conn = pool.acquire().await?;
+    let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+        .bind(COMPRESSION_LOCK_KEY)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(acquired.then(|| CompressionLock { conn: Some(conn) }))
+}
+
+impl CompressionLock {
+    pub async fn release(mut self) {
+        let Some(mut conn) = self.conn.take() else {
+            return;
+        };
+        let unlocked = sqlx::query_scalar::<_, bool>("SELECT pg_advisory_unlock($1)")
+            .bind(COMPRESSION_LOCK_KEY)
+            .fetch_one(&mut *conn)
+            .await;
+        if !matches!(unlocked, Ok(true)) {
+            tracing::warn!(
+                "Failed to release compression advisory lock, closing connection: {:?}",
+                unlocked
+            );
+            drop(conn.detach());
+        }
+    }
+}
+
+impl Drop for CompressionLock {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            drop(conn.detach());
+        }
+    }
+}
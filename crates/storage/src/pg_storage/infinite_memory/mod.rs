@@ -1,7 +1,9 @@
 mod events;
+mod lock;
 mod migrations;
 mod summaries;
 
 pub use events::*;
+pub use lock::{CompressionLock, try_acquire_compression_lock};
 pub use migrations::run_infinite_memory_migrations;
 pub use summaries::*;
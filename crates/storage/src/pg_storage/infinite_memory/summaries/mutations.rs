@@ -2,6 +2,19 @@ use crate::StorageError;
 use opencode_mem_core::{InfiniteSummary, StoredInfiniteEvent, SummaryEntities};
 use sqlx::PgPool;
 
+/// Fail the enclosing transaction when some source rows were already linked
+/// to a summary by a concurrent compression run. Dropping the transaction
+/// without committing discards the freshly inserted summary row.
+fn ensure_all_linked(table: &str, linked: u64, expected: usize) -> Result<(), StorageError> {
+    if usize::try_from(linked).is_ok_and(|n| n == expected) {
+        return Ok(());
+    }
+    Err(StorageError::Duplicate(format!(
+        "{table}: only {linked} of {expected} rows were still unsummarized; \
+         another compression run claimed the rest"
+    )))
+}
+
 pub async fn create_5min_summary(
     pool: &PgPool,
     events: &[StoredInfiniteEvent],
@@ -62,16 +75,20 @@ pub async fn create_5min_summary(
 
     let summary_id = row.0;
 
+    // Only link events that are still unsummarized. If a concurrent runner
+    // already linked any of them, roll back so no overlapping summary is kept.
     let event_ids: Vec<i64> = events.iter().map(|e| e.id).collect();
-    sqlx::query(
+    let linked = sqlx::query(
         r#"
-        UPDATE raw_events SET summary_5min_id = $1 WHERE id = ANY($2)
+        UPDATE raw_events SET summary_5min_id = $1 WHERE id = ANY($2) AND summary_5min_id IS NULL
         "#,
     )
     .bind(summary_id)
     .bind(&event_ids)
     .execute(&mut *tx)
-    .await?;
+    .await?
+    .rows_affected();
+    ensure_all_linked("raw_events", linked, event_ids.len())?;
 
     tx.commit().await?;
 
@@ -136,11 +153,15 @@ pub async fn create_hour_summary(
 
     let hour_id = row.0;
     let summary_ids: Vec<i64> = summaries.iter().map(|s| s.id).collect();
-    sqlx::query("UPDATE summaries_5min SET summary_hour_id = $1 WHERE id = ANY($2)")
-        .bind(hour_id)
-        .bind(&summary_ids)
-        .execute(&mut *tx)
-        .await?;
+    let linked = sqlx::query(
+        "UPDATE summaries_5min SET summary_hour_id = $1 WHERE id = ANY($2) AND summary_hour_id IS NULL",
+    )
+    .bind(hour_id)
+    .bind(&summary_ids)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    ensure_all_linked("summaries_5min", linked, summary_ids.len())?;
 
     tx.commit().await?;
     Ok(hour_id)
@@ -204,11 +225,15 @@ pub async fn create_day_summary(
 
     let day_id = row.0;
     let summary_ids: Vec<i64> = summaries.iter().map(|s| s.id).collect();
-    sqlx::query("UPDATE summaries_hour SET summary_day_id = $1 WHERE id = ANY($2)")
-        .bind(day_id)
-        .bind(&summary_ids)
-        .execute(&mut *tx)
-        .await?;
+    let linked = sqlx::query(
+        "UPDATE summaries_hour SET summary_day_id = $1 WHERE id = ANY($2) AND summary_day_id IS NULL",
+    )
+    .bind(day_id)
+    .bind(&summary_ids)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    ensure_all_linked("summaries_hour", linked, summary_ids.len())?;
 
     tx.commit().await?;
     Ok(day_id)
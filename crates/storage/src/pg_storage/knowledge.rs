@@ -9,8 +9,8 @@ use chrono::{DateTime, Utc};
 use opencode_mem_core::{
     EMBEDDING_DIMENSION, GlobalKnowledge, KNOWLEDGE_SEMANTIC_DEDUP_THRESHOLD,
     KNOWLEDGE_TRIGRAM_CANDIDATE_LIMIT, KNOWLEDGE_TRIGRAM_LOG_THRESHOLD,
-    KNOWLEDGE_TRIGRAM_MERGE_THRESHOLD, KnowledgeInput, KnowledgeSearchResult, KnowledgeType,
-    contains_non_finite, is_zero_vector,
+    KNOWLEDGE_TRIGRAM_MERGE_THRESHOLD, KnowledgeExportEntry, KnowledgeInput, KnowledgeSearchResult,
+    KnowledgeType, contains_non_finite, is_zero_vector,
 };
 use pgvector::Vector;
 use sqlx::Row;
@@ -595,6 +595,23 @@ impl KnowledgeStore for PgStorage {
         Ok(())
     }
 
+    async fn get_knowledge_without_embeddings(
+        &self,
+        limit: usize,
+        excluded_ids: &[String],
+    ) -> Result<Vec<GlobalKnowledge>, StorageError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {KNOWLEDGE_COLUMNS} FROM global_knowledge
+             WHERE embedding IS NULL AND archived_at IS NULL AND id != ALL($2)
+             LIMIT $1"
+        ))
+        .bind(usize_to_i64(limit))
+        .bind(excluded_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(collect_skipping_corrupt(rows.iter().map(row_to_knowledge))?)
+    }
+
     async fn get_knowledge(&self, id: &str) -> Result<Option<GlobalKnowledge>, StorageError> {
         let row = sqlx::query(&format!(
             "SELECT {KNOWLEDGE_COLUMNS} FROM global_knowledge WHERE id = $1 AND archived_at IS NULL"
@@ -784,4 +801,16 @@ impl KnowledgeStore for PgStorage {
         .await?;
         Ok(result.rows_affected() > 0)
     }
+
+    async fn export_knowledge(&self) -> Result<Vec<KnowledgeExportEntry>, StorageError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {KNOWLEDGE_COLUMNS} FROM global_knowledge
+             WHERE archived_at IS NULL
+             ORDER BY knowledge_type, title"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        let items = collect_skipping_corrupt(rows.iter().map(row_to_knowledge))?;
+        Ok(items.into_iter().map(KnowledgeExportEntry::from).collect())
+    }
 }
@@ -27,6 +27,7 @@ mod search;
 mod sessions;
 mod stats;
 mod summaries;
+mod vector_dimension;
 
 use crate::circuit_breaker::CircuitBreaker;
 use crate::error::StorageError;
@@ -49,6 +50,12 @@ pub(crate) use row_parsers::{
     row_to_observation, row_to_search_result, usize_to_i64,
 };
 
+/// Cheapest possible round-trip (`SELECT 1`), used by health probes.
+pub async fn ping_pool(pool: &PgPool) -> Result<(), StorageError> {
+    sqlx::query("SELECT 1").execute(pool).await?;
+    Ok(())
+}
+
 #[derive(Clone, Debug)]
 pub struct PgStorage {
     pool: PgPool,
@@ -65,6 +72,11 @@ impl PgStorage {
         &self.circuit_breaker
     }
 
+    /// Liveness probe for health checks.
+    pub async fn ping(&self) -> Result<(), StorageError> {
+        ping_pool(&self.pool).await
+    }
+
     #[cfg(test)]
     pub(crate) fn from_pool(pool: PgPool) -> Self {
         Self {
@@ -222,7 +234,7 @@ impl PgStorage {
     }
 }
 
-pub(crate) use search::utils::build_tsquery;
+pub(crate) use search::utils::{ObservationFilters, build_tsquery};
 
 pub(crate) const SESSION_COLUMNS: &str =
     "id, content_session_id, memory_session_id, project, user_prompt,
@@ -238,7 +250,7 @@ pub(crate) const INFINITE_SUMMARY_COLUMNS: &str =
 
 pub(crate) const SESSION_SUMMARY_COLUMNS: &str = "session_id, project, request, investigated, learned, completed, next_steps, notes, files_read, files_edited, prompt_number, discovery_tokens, created_at";
 
-pub(crate) const OBSERVATION_COLUMNS: &str = "id, session_id, project, observation_type, title, subtitle, narrative, facts, concepts, files_read, files_modified, keywords, prompt_number, discovery_tokens, noise_level, noise_reason, created_at";
+pub(crate) const OBSERVATION_COLUMNS: &str = "id, session_id, project, observation_type, title, subtitle, narrative, facts, concepts, files_read, files_modified, keywords, prompt_number, discovery_tokens, noise_level, noise_reason, created_at, expires_at";
 
 pub(crate) const EVENT_COLUMNS: &str =
     "id, ts, session_id, project, event_type, content, files, tools, call_id";
@@ -40,4 +40,43 @@ impl PgStorage {
         tx.commit().await?;
         Ok(result.rows_affected() > 0)
     }
+
+    /// Delete all observations whose `expires_at` has passed, unlinking them from knowledge
+    /// `source_observations` first. Critical observations are never expired.
+    /// Returns the number of observations removed.
+    pub async fn expire_observations(&self) -> Result<usize, StorageError> {
+        let mut tx = self.pool.begin().await?;
+
+        let ids: Vec<String> = sqlx::query_scalar(
+            "SELECT id FROM observations \
+             WHERE expires_at IS NOT NULL AND expires_at <= NOW() \
+               AND noise_level IS DISTINCT FROM 'critical' \
+             FOR UPDATE SKIP LOCKED",
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if ids.is_empty() {
+            tx.commit().await?;
+            return Ok(0);
+        }
+
+        // `- text[]` removes every listed key from the jsonb array; `?|` matches any of them.
+        sqlx::query(
+            "UPDATE global_knowledge \
+             SET source_observations = source_observations - $1::text[] \
+             WHERE source_observations ?| $1::text[]",
+        )
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await?;
+
+        let result = sqlx::query("DELETE FROM observations WHERE id = ANY($1)")
+            .bind(&ids)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(usize::try_from(result.rows_affected()).unwrap_or(usize::MAX))
+    }
 }
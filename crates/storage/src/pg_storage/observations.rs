@@ -6,6 +6,12 @@ use crate::error::StorageError;
 use crate::traits::ObservationStore;
 use async_trait::async_trait;
 use opencode_mem_core::{Observation, ObservationMetadata, SearchResult};
+use sha2::{Digest, Sha256};
+
+/// Hex SHA-256 of a tool call dedup key, as stored in `tool_call_hash`.
+fn tool_call_hash(dedup_key: &str) -> String {
+    format!("{:x}", Sha256::digest(dedup_key.as_bytes()))
+}
 
 impl PgStorage {
     async fn update_observation_fields(
@@ -67,9 +73,9 @@ impl ObservationStore for PgStorage {
             r#"INSERT INTO observations
                (id, session_id, project, observation_type, title, subtitle, narrative,
                 facts, concepts, files_read, files_modified, keywords,
-                prompt_number, discovery_tokens, noise_level, noise_reason, created_at)
-               VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17)
-               ON CONFLICT (id) DO NOTHING"#,
+                prompt_number, discovery_tokens, noise_level, noise_reason, created_at, expires_at)
+               VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18)
+               ON CONFLICT DO NOTHING"#,
         )
         .bind(&obs.id)
         .bind(&obs.session_id)
@@ -104,18 +110,12 @@ impl ObservationStore for PgStorage {
         .bind(obs.noise_level.as_str())
         .bind(&obs.noise_reason)
         .bind(obs.created_at)
+        .bind(obs.expires_at)
         .execute(&self.pool)
-        .await;
-        match result {
-            Ok(r) => Ok(r.rows_affected() > 0),
-            Err(sqlx::Error::Database(db_err)) if db_err.code().as_deref() == Some("23505") => {
-                Err(StorageError::Duplicate(format!(
-                    "Observation title '{}' already exists",
-                    obs.title
-                )))
-            }
-            Err(e) => Err(e.into()),
-        }
+        .await?;
+        // Covers both the primary key and the `title_normalized` unique index,
+        // so a case/whitespace variant of an existing title is a no-op.
+        Ok(result.rows_affected() > 0)
     }
 
     async fn get_by_id(&self, id: &str) -> Result<Option<Observation>, StorageError> {
@@ -144,6 +144,26 @@ impl ObservationStore for PgStorage {
         )?)
     }
 
+    async fn get_recent_for_project(
+        &self,
+        project: &str,
+        limit: usize,
+    ) -> Result<Vec<Observation>, StorageError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} \
+             FROM observations WHERE (project = $1 OR project IS NULL) \
+             ORDER BY created_at DESC, id DESC LIMIT $2",
+            super::OBSERVATION_COLUMNS
+        ))
+        .bind(project)
+        .bind(usize_to_i64(limit))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(collect_skipping_corrupt(
+            rows.iter().map(row_to_observation),
+        )?)
+    }
+
     async fn get_session_observations(
         &self,
         session_id: &str,
@@ -230,6 +250,35 @@ impl ObservationStore for PgStorage {
         Ok(usize::try_from(count).unwrap_or(0))
     }
 
+    async fn find_by_tool_call_hash(
+        &self,
+        session_id: &str,
+        dedup_key: &str,
+    ) -> Result<Option<Observation>, StorageError> {
+        let row = sqlx::query(&format!(
+            "SELECT {OBSERVATION_COLUMNS} FROM observations
+             WHERE session_id = $1 AND tool_call_hash = $2
+             ORDER BY created_at ASC, id ASC LIMIT 1"
+        ))
+        .bind(session_id)
+        .bind(tool_call_hash(dedup_key))
+        .fetch_optional(&self.pool)
+        .await?;
+        row.map(|r| row_to_observation(&r)).transpose()
+    }
+
+    async fn set_tool_call_hash(&self, id: &str, dedup_key: &str) -> Result<(), StorageError> {
+        sqlx::query(
+            "UPDATE observations SET tool_call_hash = $2
+             WHERE id = $1 AND tool_call_hash IS NULL",
+        )
+        .bind(id)
+        .bind(tool_call_hash(dedup_key))
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
     async fn search_by_file(
         &self,
         file_path: &str,
@@ -19,12 +19,13 @@ impl PendingQueueStore for PgStorage {
         tool_input: Option<&str>,
         tool_response: Option<&str>,
         project: Option<&str>,
+        model: Option<&str>,
     ) -> Result<i64, StorageError> {
         let now = Utc::now().timestamp();
         let id: i64 = sqlx::query_scalar(
             "INSERT INTO pending_messages
-               (session_id, call_id, status, tool_name, tool_input, tool_response, retry_count, created_at_epoch, project)
-               VALUES ($1, $2, 'pending', $3, $4, $5, 0, $6, $7)
+               (session_id, call_id, status, tool_name, tool_input, tool_response, retry_count, created_at_epoch, project, model)
+               VALUES ($1, $2, 'pending', $3, $4, $5, 0, $6, $7, $8)
                RETURNING id",
         )
         .bind(session_id)
@@ -34,6 +35,7 @@ impl PendingQueueStore for PgStorage {
         .bind(tool_response)
         .bind(now)
         .bind(project)
+        .bind(model)
         .fetch_one(&self.pool)
         .await?;
         Ok(id)
@@ -61,7 +63,7 @@ impl PendingQueueStore for PgStorage {
                    FOR UPDATE SKIP LOCKED \
                ) \
                RETURNING id, session_id, call_id, status, tool_name, tool_input, tool_response, \
-                         retry_count, created_at_epoch, claimed_at_epoch, completed_at_epoch, project",
+                         retry_count, created_at_epoch, claimed_at_epoch, completed_at_epoch, project, model",
         )
         .bind(now)
         .bind(stale_threshold)
@@ -115,6 +117,17 @@ impl PendingQueueStore for PgStorage {
         Ok(usize::try_from(count).unwrap_or(0))
     }
 
+    async fn get_session_in_flight_count(&self, session_id: &str) -> Result<usize, StorageError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM pending_messages \
+               WHERE session_id = $1 AND status IN ('pending', 'processing')",
+        )
+        .bind(session_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(usize::try_from(count).unwrap_or(0))
+    }
+
     async fn release_stale_messages(
         &self,
         visibility_timeout_secs: i64,
@@ -172,7 +185,7 @@ impl PendingQueueStore for PgStorage {
     async fn get_failed_messages(&self, limit: usize) -> Result<Vec<PendingMessage>, StorageError> {
         let rows = sqlx::query(
             "SELECT id, session_id, call_id, status, tool_name, tool_input, tool_response,
-                    retry_count, created_at_epoch, claimed_at_epoch, completed_at_epoch, project
+                    retry_count, created_at_epoch, claimed_at_epoch, completed_at_epoch, project, model
                FROM pending_messages
                WHERE status = 'failed'
                ORDER BY created_at_epoch DESC
@@ -192,7 +205,7 @@ impl PendingQueueStore for PgStorage {
     ) -> Result<Vec<PendingMessage>, StorageError> {
         let rows = sqlx::query(
             "SELECT id, session_id, call_id, status, tool_name, tool_input, tool_response,
-                    retry_count, created_at_epoch, claimed_at_epoch, completed_at_epoch, project
+                    retry_count, created_at_epoch, claimed_at_epoch, completed_at_epoch, project, model
                FROM pending_messages
                WHERE status = 'pending'
                ORDER BY created_at_epoch DESC
@@ -272,6 +285,7 @@ impl PendingQueueStore for PgStorage {
         let mut tool_inputs = Vec::with_capacity(messages.len());
         let mut tool_responses = Vec::with_capacity(messages.len());
         let mut projects = Vec::with_capacity(messages.len());
+        let mut models = Vec::with_capacity(messages.len());
         let mut created_at_epochs = Vec::with_capacity(messages.len());
 
         let now = Utc::now().timestamp();
@@ -283,13 +297,14 @@ impl PendingQueueStore for PgStorage {
             tool_inputs.push(m.tool_input.clone());
             tool_responses.push(m.tool_response.clone());
             projects.push(m.project.clone());
+            models.push(m.model.clone());
             created_at_epochs.push(now);
         }
 
         let result = sqlx::query(
             "INSERT INTO pending_messages \
-             (session_id, call_id, status, tool_name, tool_input, tool_response, retry_count, created_at_epoch, project) \
-             SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[], $7::int4[], $8::int8[], $9::text[])",
+             (session_id, call_id, status, tool_name, tool_input, tool_response, retry_count, created_at_epoch, project, model) \
+             SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[], $7::int4[], $8::int8[], $9::text[], $10::text[])",
         )
         .bind(&session_ids)
         .bind(&call_ids)
@@ -300,6 +315,7 @@ impl PendingQueueStore for PgStorage {
         .bind(vec![0i32; messages.len()])
         .bind(&created_at_epochs)
         .bind(&projects)
+        .bind(&models)
         .execute(&self.pool)
         .await?;
 
@@ -6,16 +6,15 @@ use crate::error::StorageError;
 use crate::pending_queue::PaginatedResult;
 use crate::traits::PromptStore;
 use async_trait::async_trait;
-use opencode_mem_core::UserPrompt;
+use opencode_mem_core::{Observation, UserPrompt};
 
 #[async_trait]
 impl PromptStore for PgStorage {
-    async fn save_user_prompt(&self, prompt: &UserPrompt) -> Result<(), StorageError> {
-        sqlx::query(
+    async fn save_user_prompt(&self, prompt: &UserPrompt) -> Result<bool, StorageError> {
+        let result = sqlx::query(
             "INSERT INTO user_prompts (id, content_session_id, prompt_number, prompt_text, project, created_at)
                VALUES ($1, $2, $3, $4, $5, $6)
-               ON CONFLICT (id) DO UPDATE SET
-                 prompt_text = EXCLUDED.prompt_text, project = EXCLUDED.project",
+               ON CONFLICT DO NOTHING",
         )
         .bind(&prompt.id)
         .bind(&prompt.content_session_id)
@@ -28,7 +27,43 @@ impl PromptStore for PgStorage {
         .bind(prompt.created_at)
         .execute(&self.pool)
         .await?;
-        Ok(())
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn save_next_user_prompt(&self, prompt: &UserPrompt) -> Result<u32, StorageError> {
+        // A concurrent prompt of the same content session can claim the number
+        // first; the unique index turns that into an empty result, so retry.
+        for attempt in 0u8..3u8 {
+            let number: Option<i32> = sqlx::query_scalar(
+                "INSERT INTO user_prompts (id, content_session_id, prompt_number, prompt_text, project, created_at)
+                   SELECT $1, $2, COALESCE(MAX(prompt_number), 0) + 1, $3, $4, $5
+                     FROM user_prompts WHERE content_session_id = $2
+                   ON CONFLICT DO NOTHING
+                   RETURNING prompt_number",
+            )
+            .bind(&prompt.id)
+            .bind(&prompt.content_session_id)
+            .bind(&prompt.prompt_text)
+            .bind(&prompt.project)
+            .bind(prompt.created_at)
+            .fetch_optional(&self.pool)
+            .await?;
+            if let Some(number) = number {
+                return u32::try_from(number).map_err(|e| StorageError::DataCorruption {
+                    context: "prompt_number negative in DB".into(),
+                    source: Box::new(e),
+                });
+            }
+            tracing::debug!(
+                content_session_id = %prompt.content_session_id,
+                attempt,
+                "prompt number already taken, retrying"
+            );
+        }
+        Err(StorageError::Duplicate(format!(
+            "no free prompt number for content session {}",
+            prompt.content_session_id
+        )))
     }
 
     async fn get_prompts_paginated(
@@ -86,6 +121,24 @@ impl PromptStore for PgStorage {
         row.as_ref().map(row_to_prompt).transpose()
     }
 
+    async fn get_prompt_observations(
+        &self,
+        prompt_id: &str,
+    ) -> Result<Vec<Observation>, StorageError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {OBSERVATION_COLUMNS} FROM observations
+               WHERE (session_id, prompt_number) =
+                 (SELECT content_session_id, prompt_number FROM user_prompts WHERE id = $1)
+               ORDER BY created_at ASC, id ASC"
+        ))
+        .bind(prompt_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(collect_skipping_corrupt(
+            rows.iter().map(row_to_observation),
+        )?)
+    }
+
     async fn search_prompts(
         &self,
         query: &str,
@@ -55,6 +55,7 @@ pub(crate) fn row_to_observation(row: &sqlx::postgres::PgRow) -> Result<Observat
         parse_pg_noise_level(row.try_get::<Option<String>, _>("noise_level")?.as_deref())?;
     let noise_reason: Option<String> = row.try_get("noise_reason")?;
     let created_at: DateTime<Utc> = row.try_get("created_at")?;
+    let expires_at: Option<DateTime<Utc>> = row.try_get("expires_at")?;
     let facts: serde_json::Value = row.try_get("facts")?;
     let concepts: serde_json::Value = row.try_get("concepts")?;
     let files_read: serde_json::Value = row.try_get("files_read")?;
@@ -100,6 +101,7 @@ pub(crate) fn row_to_observation(row: &sqlx::postgres::PgRow) -> Result<Observat
     .noise_level(noise_level)
     .maybe_noise_reason(noise_reason)
     .created_at(created_at)
+    .maybe_expires_at(expires_at)
     .build())
 }
 
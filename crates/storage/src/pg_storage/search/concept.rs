@@ -0,0 +1,28 @@
+use crate::error::StorageError;
+use opencode_mem_core::{Concept, SearchResult};
+
+use super::super::{PgStorage, collect_skipping_corrupt, row_to_search_result, usize_to_i64};
+
+/// Observations tagged with `concept` (GIN-indexed containment), newest first.
+/// With a project, observations without a project are included as well.
+pub(crate) async fn search_by_concept(
+    storage: &PgStorage,
+    concept: Concept,
+    project: Option<&str>,
+    limit: usize,
+) -> Result<Vec<SearchResult>, StorageError> {
+    let rows = sqlx::query(
+        "SELECT id, title, subtitle, observation_type, noise_level, 0.0::float8 AS score
+           FROM observations
+          WHERE concepts @> jsonb_build_array($1::text)
+            AND ($2::text IS NULL OR project = $2 OR project IS NULL)
+          ORDER BY created_at DESC, id
+          LIMIT $3",
+    )
+    .bind(concept.as_str())
+    .bind(project)
+    .bind(usize_to_i64(limit))
+    .fetch_all(&storage.pool)
+    .await?;
+    collect_skipping_corrupt(rows.iter().map(row_to_search_result))
+}
@@ -2,7 +2,7 @@ use crate::error::StorageError;
 use opencode_mem_core::SearchResult;
 
 use super::super::{PgStorage, collect_skipping_corrupt, row_to_search_result, usize_to_i64};
-use super::utils::build_tsquery;
+use super::utils::{ObservationFilters, build_tsquery};
 
 pub(crate) async fn search(
     storage: &PgStorage,
@@ -36,30 +36,8 @@ pub(crate) async fn search_with_filters(
     to: Option<&str>,
     limit: usize,
 ) -> Result<Vec<SearchResult>, StorageError> {
-    let mut conditions = Vec::new();
-    let mut param_idx: usize = 1;
-    let mut bind_strings: Vec<String> = Vec::new();
-
-    if let Some(p) = project {
-        conditions.push(format!("(project = ${param_idx} OR project IS NULL)"));
-        param_idx += 1;
-        bind_strings.push(p.to_owned());
-    }
-    if let Some(t) = obs_type {
-        conditions.push(format!("observation_type = ${param_idx}"));
-        param_idx += 1;
-        bind_strings.push(t.to_owned());
-    }
-    if let Some(f) = from {
-        conditions.push(format!("created_at >= ${param_idx}::timestamptz"));
-        param_idx += 1;
-        bind_strings.push(f.to_owned());
-    }
-    if let Some(t) = to {
-        conditions.push(format!("created_at <= ${param_idx}::timestamptz"));
-        param_idx += 1;
-        bind_strings.push(t.to_owned());
-    }
+    let filters = ObservationFilters::new(project, obs_type, from, to);
+    let mut param_idx = filters.next_param;
 
     if let Some(q) = query
         && let Some(tsquery) = build_tsquery(q)
@@ -70,11 +48,7 @@ pub(crate) async fn search_with_filters(
             "ts_rank_cd(search_vec, to_tsquery('simple', ${}))::float8 as score",
             param_idx - 1
         );
-        let extra_where = if conditions.is_empty() {
-            String::new()
-        } else {
-            format!("AND {}", conditions.join(" AND "))
-        };
+        let extra_where = filters.and_clause();
         let sql = format!(
             "SELECT id, title, subtitle, observation_type, noise_level, {score_expr}
                FROM observations
@@ -84,7 +58,7 @@ pub(crate) async fn search_with_filters(
         );
 
         let mut q = sqlx::query(&sql);
-        for val in &bind_strings {
+        for val in &filters.binds {
             q = q.bind(val);
         }
         q = q.bind(&tsquery);
@@ -93,11 +67,7 @@ pub(crate) async fn search_with_filters(
         return collect_skipping_corrupt(rows.iter().map(row_to_search_result));
     }
 
-    let where_clause = if conditions.is_empty() {
-        String::new()
-    } else {
-        format!("WHERE {}", conditions.join(" AND "))
-    };
+    let where_clause = filters.where_clause();
     let sql = format!(
         "SELECT id, title, subtitle, observation_type, noise_level, 0.0::float8 as score
            FROM observations {where_clause}
@@ -106,7 +76,7 @@ pub(crate) async fn search_with_filters(
     );
 
     let mut q = sqlx::query(&sql);
-    for val in &bind_strings {
+    for val in &filters.binds {
         q = q.bind(val);
     }
     q = q.bind(usize_to_i64(limit));
@@ -6,7 +6,7 @@ use opencode_mem_core::{ObservationId, SearchResult, sort_by_score_descending};
 use super::super::super::{
     PgStorage, collect_skipping_corrupt, row_to_search_result, usize_to_i64,
 };
-use super::super::utils::build_or_tsquery;
+use super::super::utils::{ObservationFilters, build_or_tsquery};
 
 /// Hybrid search v2: FTS BM25 (50%) + vector cosine similarity (50%).
 pub(crate) async fn hybrid_search_v2(
@@ -35,36 +35,9 @@ pub(crate) async fn hybrid_search_v2_with_filters(
 ) -> Result<Vec<SearchResult>, StorageError> {
     let fetch_limit = usize_to_i64(limit.saturating_mul(3));
 
-    let mut where_parts: Vec<String> = Vec::new();
-    let mut param_idx: usize = 1;
-    let mut bind_values: Vec<String> = Vec::new();
-
-    if let Some(p) = project {
-        where_parts.push(format!("(project = ${param_idx} OR project IS NULL)"));
-        param_idx += 1;
-        bind_values.push(p.to_owned());
-    }
-    if let Some(t) = obs_type {
-        where_parts.push(format!("observation_type = ${param_idx}"));
-        param_idx += 1;
-        bind_values.push(t.to_owned());
-    }
-    if let Some(f) = from {
-        where_parts.push(format!("created_at >= ${param_idx}::timestamptz"));
-        param_idx += 1;
-        bind_values.push(f.to_owned());
-    }
-    if let Some(t) = to {
-        where_parts.push(format!("created_at <= ${param_idx}::timestamptz"));
-        param_idx += 1;
-        bind_values.push(t.to_owned());
-    }
-
-    let filter_clause = if where_parts.is_empty() {
-        String::new()
-    } else {
-        format!("AND {}", where_parts.join(" AND "))
-    };
+    let filters = ObservationFilters::new(project, obs_type, from, to);
+    let param_idx = filters.next_param;
+    let filter_clause = filters.and_clause();
 
     let fts_results = match build_or_tsquery(query, 15) {
         Some(tsquery) => {
@@ -80,7 +53,7 @@ pub(crate) async fn hybrid_search_v2_with_filters(
                 n = param_idx + 1,
             );
             let mut q = sqlx::query(&fts_sql);
-            for val in &bind_values {
+            for val in &filters.binds {
                 q = q.bind(val);
             }
             q = q.bind(&tsquery);
@@ -107,7 +80,7 @@ pub(crate) async fn hybrid_search_v2_with_filters(
             n = param_idx + 1,
         );
         let mut q = sqlx::query(&vec_sql);
-        for val in &bind_values {
+        for val in &filters.binds {
             q = q.bind(val);
         }
         q = q.bind(&query_vector);
@@ -0,0 +1,37 @@
+use crate::error::StorageError;
+use opencode_mem_core::SearchResult;
+
+use super::super::{PgStorage, collect_skipping_corrupt, row_to_search_result, usize_to_i64};
+
+/// Exact match on the structured `keywords` array (GIN-indexed containment).
+///
+/// Score = 0.6 × prominence + 0.4 × recency, where prominence rewards an
+/// early position in the keyword list (1/position) plus a bonus when the
+/// keyword also appears in the title, and recency decays with a 30-day
+/// half-life.
+pub(crate) async fn search_by_keyword(
+    storage: &PgStorage,
+    keyword: &str,
+    limit: usize,
+) -> Result<Vec<SearchResult>, StorageError> {
+    let rows = sqlx::query(
+        "SELECT o.id, o.title, o.subtitle, o.observation_type, o.noise_level,
+                (0.6 * (1.0 / k.position
+                        + CASE WHEN strpos(lower(o.title), lower($1)) > 0 THEN 0.5 ELSE 0.0 END) / 1.5
+                 + 0.4 / (1.0 + EXTRACT(EPOCH FROM (NOW() - o.created_at)) / 2592000.0))::float8 AS score
+           FROM observations o
+           CROSS JOIN LATERAL (
+               SELECT MIN(e.ordinality) AS position
+                 FROM jsonb_array_elements_text(o.keywords) WITH ORDINALITY AS e(keyword, ordinality)
+                WHERE e.keyword = $1
+           ) k
+          WHERE o.keywords @> jsonb_build_array($1::text)
+          ORDER BY score DESC, o.created_at DESC, o.id
+          LIMIT $2",
+    )
+    .bind(keyword)
+    .bind(usize_to_i64(limit))
+    .fetch_all(&storage.pool)
+    .await?;
+    collect_skipping_corrupt(rows.iter().map(row_to_search_result))
+}
@@ -1,5 +1,7 @@
+mod concept;
 mod fts;
 mod hybrid;
+mod keyword;
 mod semantic;
 mod timeline;
 pub(crate) mod utils;
@@ -7,7 +9,7 @@ pub(crate) mod utils;
 use crate::error::StorageError;
 use crate::traits::SearchStore;
 use async_trait::async_trait;
-use opencode_mem_core::SearchResult;
+use opencode_mem_core::{Concept, SearchResult};
 
 use super::PgStorage;
 
@@ -78,4 +80,21 @@ impl SearchStore for PgStorage {
         )
         .await
     }
+
+    async fn search_by_keyword(
+        &self,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, StorageError> {
+        keyword::search_by_keyword(self, keyword, limit).await
+    }
+
+    async fn search_by_concept(
+        &self,
+        concept: Concept,
+        project: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, StorageError> {
+        concept::search_by_concept(self, concept, project, limit).await
+    }
 }
@@ -1,4 +1,9 @@
+use opencode_mem_core::normalize_query;
+
 fn tokenize_tsquery(query: &str) -> Vec<String> {
+    let Some(query) = normalize_query(query) else {
+        return Vec::new();
+    };
     query
         .split(|c: char| !c.is_alphanumeric() && c != '_')
         .filter_map(|w| {
@@ -38,3 +43,91 @@ pub(crate) fn build_or_tsquery(query: &str, max_terms: usize) -> Option<String>
     words.truncate(max_terms);
     build_joined_tsquery(words, " | ")
 }
+
+/// Parameterized predicates for the project / type / date-range filters shared
+/// by filtered search and observation counts. Placeholders are numbered from
+/// `$1` in bind order; `next_param` is the first index free for the caller.
+pub(crate) struct ObservationFilters {
+    conditions: Vec<String>,
+    pub(crate) binds: Vec<String>,
+    pub(crate) next_param: usize,
+}
+
+impl ObservationFilters {
+    pub(crate) fn new(
+        project: Option<&str>,
+        obs_type: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Self {
+        let mut filters = Self {
+            conditions: Vec::new(),
+            binds: Vec::new(),
+            next_param: 1,
+        };
+        if let Some(p) = project {
+            filters.push("(project = $ OR project IS NULL)", p);
+        }
+        if let Some(t) = obs_type {
+            filters.push("observation_type = $", t);
+        }
+        if let Some(f) = from {
+            filters.push("created_at >= $::timestamptz", f);
+        }
+        if let Some(t) = to {
+            filters.push("created_at <= $::timestamptz", t);
+        }
+        filters
+    }
+
+    /// `template` holds a single `$` that receives the next placeholder index.
+    fn push(&mut self, template: &str, value: &str) {
+        let placeholder = format!("${}", self.next_param);
+        self.conditions
+            .push(template.replacen('$', &placeholder, 1));
+        self.binds.push(value.to_owned());
+        self.next_param = self.next_param.saturating_add(1);
+    }
+
+    /// `WHERE ...` for a query with no other predicates, or empty.
+    pub(crate) fn where_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", self.conditions.join(" AND "))
+        }
+    }
+
+    /// `AND ...` to append after an existing predicate, or empty.
+    pub(crate) fn and_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            String::new()
+        } else {
+            format!("AND {}", self.conditions.join(" AND "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observation_filters_number_placeholders_in_bind_order() {
+        let filters = ObservationFilters::new(Some("proj"), None, Some("2026-01-01"), None);
+        assert_eq!(
+            filters.where_clause(),
+            "WHERE (project = $1 OR project IS NULL) AND created_at >= $2::timestamptz"
+        );
+        assert_eq!(filters.binds, vec!["proj", "2026-01-01"]);
+        assert_eq!(filters.next_param, 3);
+    }
+
+    #[test]
+    fn test_observation_filters_empty_without_filters() {
+        let filters = ObservationFilters::new(None, None, None, None);
+        assert!(filters.where_clause().is_empty());
+        assert!(filters.and_clause().is_empty());
+        assert_eq!(filters.next_param, 1);
+    }
+}
@@ -66,6 +66,21 @@ impl SessionStore for PgStorage {
         row.map(|r| row_to_session(&r)).transpose()
     }
 
+    async fn get_current_prompt_number(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<u32>, StorageError> {
+        let number: Option<i32> = sqlx::query_scalar(
+            "SELECT MAX(prompt_number) FROM user_prompts
+             WHERE content_session_id = $1
+                OR content_session_id = (SELECT content_session_id FROM sessions WHERE id = $1)",
+        )
+        .bind(session_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(number.and_then(|n| u32::try_from(n).ok()))
+    }
+
     async fn update_session_status(
         &self,
         id: &str,
@@ -3,10 +3,10 @@
 use super::*;
 
 use crate::error::StorageError;
-use crate::pending_queue::{PaginatedResult, StorageStats};
+use crate::pending_queue::{PaginatedResult, ProjectFileActivity, StorageStats};
 use crate::traits::StatsStore;
 use async_trait::async_trait;
-use opencode_mem_core::Observation;
+use opencode_mem_core::{Observation, ObservationSort};
 
 #[async_trait]
 impl StatsStore for PgStorage {
@@ -46,12 +46,72 @@ impl StatsStore for PgStorage {
         Ok(rows)
     }
 
+    async fn get_project_files(
+        &self,
+        project: &str,
+        limit: usize,
+    ) -> Result<Vec<ProjectFileActivity>, StorageError> {
+        let rows: Vec<(String, i64, i64, chrono::DateTime<chrono::Utc>)> = sqlx::query_as(
+            "WITH touched AS ( \
+                SELECT f.path, TRUE AS modified, o.created_at \
+                  FROM observations o \
+                  CROSS JOIN LATERAL jsonb_array_elements_text(o.files_modified) AS f(path) \
+                 WHERE o.project = $1 AND jsonb_typeof(o.files_modified) = 'array' \
+                UNION ALL \
+                SELECT f.path, FALSE, o.created_at \
+                  FROM observations o \
+                  CROSS JOIN LATERAL jsonb_array_elements_text(o.files_read) AS f(path) \
+                 WHERE o.project = $1 AND jsonb_typeof(o.files_read) = 'array' \
+             ) \
+             SELECT path, \
+                    COUNT(*) FILTER (WHERE modified), \
+                    COUNT(*) FILTER (WHERE NOT modified), \
+                    MAX(created_at) \
+               FROM touched \
+              WHERE path <> '' \
+              GROUP BY path \
+              ORDER BY MAX(created_at) DESC, path \
+              LIMIT $2",
+        )
+        .bind(project)
+        .bind(usize_to_i64(limit))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(path, modified, read, last_touched)| {
+                ProjectFileActivity::new(path, modified, read, last_touched)
+            })
+            .collect())
+    }
+
+    async fn count_observations(
+        &self,
+        project: Option<&str>,
+        obs_type: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<usize, StorageError> {
+        let filters = ObservationFilters::new(project, obs_type, from, to);
+        let where_clause = filters.where_clause();
+        let sql = format!("SELECT COUNT(*) FROM observations {where_clause}");
+
+        let mut q = sqlx::query_scalar::<_, i64>(&sql);
+        for val in &filters.binds {
+            q = q.bind(val);
+        }
+        let count = q.fetch_one(&self.pool).await?;
+        Ok(usize::try_from(count).unwrap_or(0))
+    }
+
     async fn get_observations_paginated(
         &self,
         offset: usize,
         limit: usize,
         project: Option<&str>,
+        sort: ObservationSort,
     ) -> Result<PaginatedResult<Observation>, StorageError> {
+        let order_by = observation_order_by(sort);
         let total: i64 = if let Some(p) = project {
             sqlx::query_scalar(
                 "SELECT COUNT(*) FROM observations WHERE (project = $1 OR project IS NULL)",
@@ -68,7 +128,7 @@ impl StatsStore for PgStorage {
         let rows = if let Some(p) = project {
             sqlx::query(&format!(
                 "SELECT {OBSERVATION_COLUMNS} \
-                   FROM observations WHERE (project = $1 OR project IS NULL) ORDER BY created_at DESC, id LIMIT $2 OFFSET $3",
+                   FROM observations WHERE (project = $1 OR project IS NULL) ORDER BY {order_by} LIMIT $2 OFFSET $3",
             ))
             .bind(p)
             .bind(usize_to_i64(limit))
@@ -78,7 +138,7 @@ impl StatsStore for PgStorage {
         } else {
             sqlx::query(&format!(
                 "SELECT {OBSERVATION_COLUMNS} \
-                   FROM observations ORDER BY created_at DESC, id LIMIT $1 OFFSET $2",
+                   FROM observations ORDER BY {order_by} LIMIT $1 OFFSET $2",
             ))
             .bind(usize_to_i64(limit))
             .bind(usize_to_i64(offset))
@@ -90,3 +150,18 @@ impl StatsStore for PgStorage {
         Ok(PaginatedResult::new(items, total, offset, limit))
     }
 }
+
+/// `ORDER BY` clause for a listing sort, always ending on `id` so pages are stable.
+/// Listings carry no relevance score, so `Score` falls back to newest first.
+fn observation_order_by(sort: ObservationSort) -> &'static str {
+    match sort {
+        ObservationSort::CreatedDesc | ObservationSort::Score => "created_at DESC, id",
+        ObservationSort::CreatedAsc => "created_at ASC, id",
+        ObservationSort::Type => "observation_type, created_at DESC, id",
+        ObservationSort::Noise => {
+            "CASE noise_level \
+               WHEN 'critical' THEN 0 WHEN 'high' THEN 1 WHEN 'low' THEN 3 \
+               WHEN 'negligible' THEN 4 ELSE 2 END, created_at DESC, id"
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Startup check that the pgvector columns match the embedding model's dimension.
+
+use super::*;
+
+use crate::error::StorageError;
+
+/// Tables with an `embedding` vector column, and the index rebuilt after a resize.
+/// Index definitions mirror the latest migrations for each table.
+const VECTOR_COLUMNS: [(&str, &str); 2] = [
+    (
+        "observations",
+        "CREATE INDEX idx_obs_embedding ON observations \
+           USING hnsw (embedding vector_cosine_ops) WITH (m = 16, ef_construction = 64)",
+    ),
+    (
+        "global_knowledge",
+        "CREATE INDEX idx_gk_embedding ON global_knowledge \
+           USING ivfflat (embedding vector_cosine_ops) WITH (lists = 10)",
+    ),
+];
+
+impl PgStorage {
+    /// Declared dimension of `table.embedding`, or `None` if the column is
+    /// missing or unconstrained.
+    async fn embedding_column_dimension(&self, table: &str) -> Result<Option<usize>, StorageError> {
+        let typmod: Option<i32> = sqlx::query_scalar(
+            "SELECT atttypmod FROM pg_attribute
+             WHERE attrelid = $1::regclass AND attname = 'embedding' AND NOT attisdropped",
+        )
+        .bind(table)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(typmod.and_then(|t| usize::try_from(t).ok()))
+    }
+
+    /// Compare every vector column against `expected`.
+    ///
+    /// `expected` is the compile-time `EMBEDDING_DIMENSION` of the bundled
+    /// model, not a value probed from the loaded model, so this catches a
+    /// database sized by a build that shipped a different model.
+    ///
+    /// On mismatch, returns [`StorageError::EmbeddingDimensionMismatch`] unless
+    /// `recreate` is set, in which case the columns are dropped and re-added at
+    /// the new size — discarding all stored vectors — and `Ok(true)` is returned
+    /// so the caller can schedule re-embed backfills for both observations and
+    /// knowledge.
+    pub async fn ensure_embedding_dimension(
+        &self,
+        expected: usize,
+        recreate: bool,
+    ) -> Result<bool, StorageError> {
+        let mut mismatched = Vec::new();
+        for (table, create_index) in VECTOR_COLUMNS {
+            if let Some(found) = self.embedding_column_dimension(table).await?
+                && found != expected
+            {
+                if !recreate {
+                    return Err(StorageError::EmbeddingDimensionMismatch {
+                        table,
+                        expected,
+                        found,
+                    });
+                }
+                mismatched.push((table, create_index, found));
+            }
+        }
+        if mismatched.is_empty() {
+            return Ok(false);
+        }
+
+        let mut tx = self.pool.begin().await?;
+        for (table, create_index, found) in mismatched {
+            tracing::warn!(
+                table,
+                from = found,
+                to = expected,
+                "Recreating embedding column at new dimension; stored vectors are discarded"
+            );
+            sqlx::query(&format!("ALTER TABLE {table} DROP COLUMN embedding"))
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query(&format!(
+                "ALTER TABLE {table} ADD COLUMN embedding vector({expected})"
+            ))
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(create_index).execute(&mut *tx).await?;
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+}
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
-use opencode_mem_core::{GlobalKnowledge, KnowledgeInput, KnowledgeSearchResult, KnowledgeType};
+use opencode_mem_core::{
+    GlobalKnowledge, KnowledgeExportEntry, KnowledgeInput, KnowledgeSearchResult, KnowledgeType,
+};
 
 use crate::error::StorageError;
 
@@ -34,6 +36,13 @@ pub trait KnowledgeStore: Send + Sync {
         embedding: &[f32],
     ) -> Result<(), StorageError>;
 
+    /// Get active knowledge entries without an embedding, excluding specific IDs.
+    async fn get_knowledge_without_embeddings(
+        &self,
+        limit: usize,
+        excluded_ids: &[String],
+    ) -> Result<Vec<GlobalKnowledge>, StorageError>;
+
     /// Get knowledge entry by ID.
     async fn get_knowledge(&self, id: &str) -> Result<Option<GlobalKnowledge>, StorageError>;
 
@@ -81,4 +90,7 @@ pub trait KnowledgeStore: Send + Sync {
         knowledge_id: &str,
         observation_id: &str,
     ) -> Result<bool, StorageError>;
+
+    /// Export all non-archived knowledge entries in portable form.
+    async fn export_knowledge(&self) -> Result<Vec<KnowledgeExportEntry>, StorageError>;
 }
@@ -7,6 +7,9 @@ use crate::error::StorageError;
 #[async_trait]
 pub trait ObservationStore: Send + Sync {
     /// Save observation. Returns `true` if inserted, `false` on duplicate.
+    ///
+    /// A duplicate is either an existing ID or an existing title that matches
+    /// after trimming and lowercasing (`title_normalized`).
     async fn save_observation(&self, obs: &Observation) -> Result<bool, StorageError>;
 
     /// Get observation by ID.
@@ -15,6 +18,13 @@ pub trait ObservationStore: Send + Sync {
     /// Get recent observations.
     async fn get_recent(&self, limit: usize) -> Result<Vec<Observation>, StorageError>;
 
+    /// Get recent observations for a project, including project-less ones.
+    async fn get_recent_for_project(
+        &self,
+        project: &str,
+        limit: usize,
+    ) -> Result<Vec<Observation>, StorageError>;
+
     /// Get all observations for a session.
     async fn get_session_observations(
         &self,
@@ -43,6 +53,17 @@ pub trait ObservationStore: Send + Sync {
     /// Count observations in a session.
     async fn get_session_observation_count(&self, session_id: &str) -> Result<usize, StorageError>;
 
+    /// Find the observation a session already produced for an identical tool
+    /// call. `dedup_key` is hashed (SHA-256) before comparison against `tool_call_hash`.
+    async fn find_by_tool_call_hash(
+        &self,
+        session_id: &str,
+        dedup_key: &str,
+    ) -> Result<Option<Observation>, StorageError>;
+
+    /// Record the hash of `dedup_key` on an observation unless one is already set.
+    async fn set_tool_call_hash(&self, id: &str, dedup_key: &str) -> Result<(), StorageError>;
+
     /// Search observations by file path.
     async fn search_by_file(
         &self,
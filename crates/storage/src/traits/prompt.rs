@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use opencode_mem_core::UserPrompt;
+use opencode_mem_core::{Observation, UserPrompt};
 
 use crate::error::StorageError;
 use crate::pending_queue::PaginatedResult;
@@ -7,8 +7,13 @@ use crate::pending_queue::PaginatedResult;
 /// User prompt operations.
 #[async_trait]
 pub trait PromptStore: Send + Sync {
-    /// Save user prompt.
-    async fn save_user_prompt(&self, prompt: &UserPrompt) -> Result<(), StorageError>;
+    /// Save user prompt. Returns `false` when a prompt with the same id or the
+    /// same `(content_session_id, prompt_number)` already exists.
+    async fn save_user_prompt(&self, prompt: &UserPrompt) -> Result<bool, StorageError>;
+
+    /// Save user prompt under the next prompt number of its content session,
+    /// ignoring `prompt.prompt_number`. Returns the number assigned.
+    async fn save_next_user_prompt(&self, prompt: &UserPrompt) -> Result<u32, StorageError>;
 
     /// Get prompts with pagination.
     async fn get_prompts_paginated(
@@ -21,6 +26,13 @@ pub trait PromptStore: Send + Sync {
     /// Get prompt by ID.
     async fn get_prompt_by_id(&self, id: &str) -> Result<Option<UserPrompt>, StorageError>;
 
+    /// Observations recorded in the prompt's session under its prompt number,
+    /// oldest first. Empty when the prompt does not exist.
+    async fn get_prompt_observations(
+        &self,
+        prompt_id: &str,
+    ) -> Result<Vec<Observation>, StorageError>;
+
     /// Search prompts by text.
     async fn search_prompts(
         &self,
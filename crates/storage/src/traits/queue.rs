@@ -7,6 +7,12 @@ use crate::pending_queue::{PendingMessage, QueueStats};
 #[async_trait]
 pub trait PendingQueueStore: Send + Sync {
     /// Queue a message for processing. Returns the new message ID.
+    ///
+    /// `model` optionally overrides the LLM model used when the message is processed.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "Queue parameters mirror the pending_messages columns"
+    )]
     async fn queue_message(
         &self,
         session_id: &str,
@@ -15,6 +21,7 @@ pub trait PendingQueueStore: Send + Sync {
         tool_input: Option<&str>,
         tool_response: Option<&str>,
         project: Option<&str>,
+        model: Option<&str>,
     ) -> Result<i64, StorageError>;
 
     /// Claim pending messages for processing.
@@ -25,6 +32,10 @@ pub trait PendingQueueStore: Send + Sync {
     ) -> Result<Vec<PendingMessage>, StorageError>;
 
     /// Delete message after successful processing.
+    ///
+    /// Completed messages are never retained, so the queue needs no separate
+    /// retention sweep; only failed messages linger (see
+    /// [`clear_stale_failed_messages`](Self::clear_stale_failed_messages)).
     async fn complete_message(&self, id: i64) -> Result<(), StorageError>;
 
     /// Mark message as failed.
@@ -33,6 +44,9 @@ pub trait PendingQueueStore: Send + Sync {
     /// Get count of pending messages.
     async fn get_pending_count(&self) -> Result<usize, StorageError>;
 
+    /// Count pending and processing messages belonging to a session.
+    async fn get_session_in_flight_count(&self, session_id: &str) -> Result<usize, StorageError>;
+
     /// Release stale processing messages back to pending.
     async fn release_stale_messages(
         &self,
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use opencode_mem_core::SearchResult;
+use opencode_mem_core::{Concept, SearchResult};
 
 use crate::error::StorageError;
 
@@ -65,4 +65,20 @@ pub trait SearchStore: Send + Sync {
         to: Option<&str>,
         limit: usize,
     ) -> Result<Vec<SearchResult>, StorageError>;
+
+    /// Observations whose `keywords` contain `keyword` exactly, ranked by
+    /// keyword prominence and recency.
+    async fn search_by_keyword(
+        &self,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, StorageError>;
+
+    /// Observations tagged with `concept`, newest first.
+    async fn search_by_concept(
+        &self,
+        concept: Concept,
+        project: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, StorageError>;
 }
@@ -19,6 +19,13 @@ pub trait SessionStore: Send + Sync {
         content_session_id: &str,
     ) -> Result<Option<Session>, StorageError>;
 
+    /// Highest recorded prompt number of the content session that `session_id`
+    /// names directly or through a session ID. `None` when no prompt was recorded.
+    async fn get_current_prompt_number(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<u32>, StorageError>;
+
     /// Update session status.
     async fn update_session_status(
         &self,
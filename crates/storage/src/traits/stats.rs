@@ -1,8 +1,8 @@
 use async_trait::async_trait;
-use opencode_mem_core::Observation;
+use opencode_mem_core::{Observation, ObservationSort};
 
 use crate::error::StorageError;
-use crate::pending_queue::{PaginatedResult, StorageStats};
+use crate::pending_queue::{PaginatedResult, ProjectFileActivity, StorageStats};
 
 /// Aggregate statistics.
 #[async_trait]
@@ -13,11 +13,30 @@ pub trait StatsStore: Send + Sync {
     /// Get all distinct projects.
     async fn get_all_projects(&self) -> Result<Vec<String>, StorageError>;
 
-    /// Get observations with pagination.
+    /// Distinct files read or modified across a project's observations,
+    /// most recently touched first.
+    async fn get_project_files(
+        &self,
+        project: &str,
+        limit: usize,
+    ) -> Result<Vec<ProjectFileActivity>, StorageError>;
+
+    /// Count observations matching the same filters as `search_with_filters`
+    /// (without a text query).
+    async fn count_observations(
+        &self,
+        project: Option<&str>,
+        obs_type: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<usize, StorageError>;
+
+    /// Get observations with pagination in the requested order.
     async fn get_observations_paginated(
         &self,
         offset: usize,
         limit: usize,
         project: Option<&str>,
+        sort: ObservationSort,
     ) -> Result<PaginatedResult<Observation>, StorageError>;
 }
@@ -1,6 +1,7 @@
 use super::test_fixtures::{create_pg_storage, make_observation, unique_id};
-use opencode_mem_core::EMBEDDING_DIMENSION;
-use opencode_mem_storage::traits::{EmbeddingStore, ObservationStore, SearchStore};
+use opencode_mem_core::{EMBEDDING_DIMENSION, KnowledgeInput, KnowledgeType};
+use opencode_mem_storage::StorageError;
+use opencode_mem_storage::traits::{EmbeddingStore, KnowledgeStore, ObservationStore, SearchStore};
 
 #[tokio::test]
 #[ignore]
@@ -42,3 +43,74 @@ async fn pg_store_and_search_embedding() {
         "Observation should be found via semantic search with matching vector"
     );
 }
+
+#[tokio::test]
+#[ignore]
+async fn pg_embedding_dimension_check_detects_mismatch() {
+    let storage = create_pg_storage().await;
+
+    let recreated = storage
+        .ensure_embedding_dimension(EMBEDDING_DIMENSION, false)
+        .await
+        .unwrap();
+    assert!(
+        !recreated,
+        "Matching dimension must leave columns untouched"
+    );
+
+    let err = storage
+        .ensure_embedding_dimension(EMBEDDING_DIMENSION + 1, false)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            StorageError::EmbeddingDimensionMismatch { found, .. } if found == EMBEDDING_DIMENSION
+        ),
+        "Expected dimension mismatch, got {err:?}"
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_knowledge_without_embeddings_feeds_backfill() {
+    let storage = create_pg_storage().await;
+    let input = KnowledgeInput::new(
+        KnowledgeType::Pattern,
+        format!("Backfill knowledge {}", unique_id()),
+        "Needs a vector".to_owned(),
+        None,
+        vec![],
+        None,
+        None,
+    );
+    let saved = storage.save_knowledge(input).await.unwrap();
+
+    let pending = storage
+        .get_knowledge_without_embeddings(1000, &[])
+        .await
+        .unwrap();
+    assert!(pending.iter().any(|k| k.id == saved.id));
+
+    let excluded = storage
+        .get_knowledge_without_embeddings(1000, std::slice::from_ref(&saved.id))
+        .await
+        .unwrap();
+    assert!(!excluded.iter().any(|k| k.id == saved.id));
+
+    let mut embedding = vec![0.0_f32; EMBEDDING_DIMENSION];
+    if let Some(e) = embedding.get_mut(0) {
+        *e = 1.0;
+    }
+    storage
+        .store_knowledge_embedding(&saved.id, &embedding)
+        .await
+        .unwrap();
+    let pending = storage
+        .get_knowledge_without_embeddings(1000, &[])
+        .await
+        .unwrap();
+    assert!(!pending.iter().any(|k| k.id == saved.id));
+
+    storage.delete_knowledge(&saved.id).await.unwrap();
+}
@@ -0,0 +1,137 @@
+use super::test_fixtures::{create_pg_storage, unique_id};
+use chrono::Utc;
+use opencode_mem_core::{InfiniteEventType, RawInfiniteEvent, StoredInfiniteEvent};
+use opencode_mem_storage::StorageError;
+use opencode_mem_storage::pg_storage::infinite_memory;
+
+async fn store_events(pool: &sqlx::PgPool, session_id: &str, n: usize) -> Vec<StoredInfiniteEvent> {
+    let mut events = Vec::with_capacity(n);
+    for i in 0..n {
+        let raw = RawInfiniteEvent {
+            session_id: session_id.to_owned(),
+            project: Some("pg-test-project".to_owned()),
+            event_type: InfiniteEventType::Tool,
+            content: serde_json::json!({ "step": i }),
+            files: Vec::new(),
+            tools: vec!["bash".to_owned()],
+            call_id: None,
+        };
+        let id = infinite_memory::store_infinite_event(pool, raw.clone())
+            .await
+            .unwrap();
+        events.push(StoredInfiniteEvent {
+            id,
+            ts: Utc::now(),
+            session_id: raw.session_id,
+            project: raw.project,
+            event_type: raw.event_type,
+            content: raw.content,
+            files: raw.files,
+            tools: raw.tools,
+            call_id: raw.call_id,
+        });
+    }
+    events
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_concurrent_5min_summaries_do_not_overlap() {
+    let storage = create_pg_storage().await;
+    let pool = storage.pool();
+    infinite_memory::run_infinite_memory_migrations(&pool)
+        .await
+        .unwrap();
+    let session_id = unique_id();
+    let events = store_events(&pool, &session_id, 3).await;
+
+    let (a, b) = tokio::join!(
+        infinite_memory::create_5min_summary(&pool, &events, "runner A", None),
+        infinite_memory::create_5min_summary(&pool, &events, "runner B", None),
+    );
+
+    let succeeded = [&a, &b].iter().filter(|r| r.is_ok()).count();
+    assert_eq!(succeeded, 1, "exactly one runner should win: {a:?} / {b:?}");
+    assert!(
+        matches!(a, Err(StorageError::Duplicate(_)))
+            || matches!(b, Err(StorageError::Duplicate(_))),
+        "the losing runner should report a conflict"
+    );
+
+    let summaries: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM summaries_5min WHERE session_id = $1")
+            .bind(&session_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(summaries, 1, "the losing summary row must be rolled back");
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_compression_lock_is_exclusive() {
+    let storage = create_pg_storage().await;
+    let pool = storage.pool();
+
+    let Some(first) = infinite_memory::try_acquire_compression_lock(&pool)
+        .await
+        .unwrap()
+    else {
+        // Another test process holds the lock; nothing meaningful to assert.
+        return;
+    };
+    let second = infinite_memory::try_acquire_compression_lock(&pool)
+        .await
+        .unwrap();
+    assert!(second.is_none(), "lock must not be granted twice");
+
+    first.release().await;
+    let third = infinite_memory::try_acquire_compression_lock(&pool)
+        .await
+        .unwrap();
+    assert!(third.is_some(), "lock should be available after release");
+    if let Some(lock) = third {
+        lock.release().await;
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_batch_insert_stores_all_events_in_one_statement() {
+    let storage = create_pg_storage().await;
+    let pool = storage.pool();
+    infinite_memory::run_infinite_memory_migrations(&pool)
+        .await
+        .unwrap();
+    let session_id = unique_id();
+    let duplicate_call = unique_id();
+    let batch: Vec<RawInfiniteEvent> = (0..5)
+        .map(|i| RawInfiniteEvent {
+            session_id: session_id.clone(),
+            project: Some("pg-test-project".to_owned()),
+            event_type: InfiniteEventType::Tool,
+            content: serde_json::json!({ "step": i }),
+            files: Vec::new(),
+            tools: vec!["bash".to_owned()],
+            call_id: (i == 0).then(|| duplicate_call.clone()),
+        })
+        .collect();
+
+    let inserted = infinite_memory::store_infinite_events(&pool, &batch)
+        .await
+        .unwrap();
+    assert_eq!(inserted, 5);
+
+    // Re-sending an event with a known call_id is skipped, not an error.
+    let inserted = infinite_memory::store_infinite_events(&pool, &batch[..1])
+        .await
+        .unwrap();
+    assert_eq!(inserted, 0);
+
+    let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM raw_events WHERE session_id = $1")
+        .bind(&session_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored, 5);
+}
@@ -75,3 +75,32 @@ async fn pg_knowledge_dedup() {
 
     storage.delete_knowledge(&saved1.id).await.unwrap();
 }
+
+#[tokio::test]
+#[ignore]
+async fn pg_knowledge_export_lists_saved_entry() {
+    let storage = create_pg_storage().await;
+    let tag = unique_id();
+    let title = format!("Export knowledge {tag}");
+
+    let input = KnowledgeInput::new(
+        KnowledgeType::Gotcha,
+        title.clone(),
+        format!("Description for gotcha {tag}"),
+        None,
+        vec!["export".to_owned()],
+        Some("pg-test-project".to_owned()),
+        None,
+    );
+    let saved = storage.save_knowledge(input).await.unwrap();
+
+    let exported = storage.export_knowledge().await.unwrap();
+    let entry = exported
+        .iter()
+        .find(|e| e.title == title)
+        .cloned()
+        .expect("Saved knowledge should be exported");
+    assert_eq!(entry.source_projects, vec!["pg-test-project".to_owned()]);
+
+    storage.delete_knowledge(&saved.id).await.unwrap();
+}
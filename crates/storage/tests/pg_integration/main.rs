@@ -4,6 +4,7 @@
 #![allow(clippy::unwrap_used, reason = "integration test code")]
 
 mod embedding_tests;
+mod infinite_memory_tests;
 mod knowledge_tests;
 mod observation_tests;
 mod queue_tests;
@@ -39,7 +39,7 @@ async fn pg_observation_dedup() {
     let first = storage.save_observation(&obs).await.unwrap();
     assert!(first, "First insert should succeed");
 
-    // Same ID → ON CONFLICT (id) DO NOTHING → returns false
+    // Same ID → ON CONFLICT DO NOTHING → returns false
     let second = storage.save_observation(&obs).await.unwrap();
     assert!(!second, "Second insert with same ID should return false");
 }
@@ -64,3 +64,132 @@ async fn pg_get_recent_observations() {
         "Should return at least 2 recent observations"
     );
 }
+
+#[tokio::test]
+#[ignore]
+async fn pg_observation_dedup_normalized_title() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    let suffix = unique_id();
+
+    let first = make_observation(
+        &unique_id(),
+        "pg-test-session",
+        &project,
+        &format!("Normalized Title {suffix}"),
+    );
+    assert!(storage.save_observation(&first).await.unwrap());
+
+    // Different ID, title differs only in case and surrounding whitespace.
+    let second_id = unique_id();
+    let second = make_observation(
+        &second_id,
+        "pg-test-session",
+        &project,
+        &format!("  normalized TITLE {}  ", suffix.to_uppercase()),
+    );
+    let inserted = storage.save_observation(&second).await.unwrap();
+    assert!(
+        !inserted,
+        "Differently-cased duplicate title should be rejected"
+    );
+    assert!(storage.get_by_id(&second_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_expire_observations_removes_expired() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    let past = chrono::Utc::now() - chrono::Duration::hours(1);
+    let future = chrono::Utc::now() + chrono::Duration::hours(1);
+
+    let expired_id = unique_id();
+    let mut expired = make_observation(
+        &expired_id,
+        "pg-test-session",
+        &project,
+        &format!("Expired {expired_id}"),
+    );
+    expired.expires_at = Some(past);
+    assert!(storage.save_observation(&expired).await.unwrap());
+
+    let critical_id = unique_id();
+    let mut critical = make_observation(
+        &critical_id,
+        "pg-test-session",
+        &project,
+        &format!("Critical {critical_id}"),
+    );
+    critical.expires_at = Some(past);
+    critical.noise_level = opencode_mem_core::NoiseLevel::Critical;
+    assert!(storage.save_observation(&critical).await.unwrap());
+
+    let live_id = unique_id();
+    let mut live = make_observation(
+        &live_id,
+        "pg-test-session",
+        &project,
+        &format!("Live {live_id}"),
+    );
+    live.expires_at = Some(future);
+    assert!(storage.save_observation(&live).await.unwrap());
+
+    let removed = storage.expire_observations().await.unwrap();
+    assert!(removed >= 1, "Sweep should remove the expired observation");
+
+    assert!(storage.get_by_id(&expired_id).await.unwrap().is_none());
+    assert!(
+        storage.get_by_id(&critical_id).await.unwrap().is_some(),
+        "Critical observations are exempt from expiry"
+    );
+    let live = storage.get_by_id(&live_id).await.unwrap().unwrap();
+    assert_eq!(
+        live.expires_at.map(|t| t.timestamp()),
+        Some(future.timestamp())
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_tool_call_hash_matches_retry_in_same_session() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    let session = unique_id();
+    let key = format!("bash\u{1f}cargo test {session}\u{1f}2 passed");
+
+    let first_id = unique_id();
+    let first = make_observation(&first_id, &session, &project, "Cargo tests pass");
+    assert!(storage.save_observation(&first).await.unwrap());
+    storage.set_tool_call_hash(&first_id, &key).await.unwrap();
+
+    // A retry worded differently by the LLM still maps to the first observation.
+    let found = storage
+        .find_by_tool_call_hash(&session, &key)
+        .await
+        .unwrap()
+        .expect("retry should match the recorded tool call");
+    assert_eq!(found.id.0, first_id);
+
+    assert!(
+        storage
+            .find_by_tool_call_hash(&unique_id(), &key)
+            .await
+            .unwrap()
+            .is_none(),
+        "Hashes are scoped to the session"
+    );
+
+    // An existing hash is never overwritten.
+    storage
+        .set_tool_call_hash(&first_id, "other")
+        .await
+        .unwrap();
+    assert!(
+        storage
+            .find_by_tool_call_hash(&session, &key)
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
@@ -15,6 +15,7 @@ async fn pg_pending_queue_lifecycle() {
             Some(r#"{"key":"value"}"#),
             Some("tool response"),
             Some("pg-test-project"),
+            Some("test-model"),
         )
         .await
         .unwrap();
@@ -23,6 +24,11 @@ async fn pg_pending_queue_lifecycle() {
     let claimed = storage.claim_pending_messages(10, 300).await.unwrap();
     let ours = claimed.iter().find(|m| m.id == msg_id);
     assert!(ours.is_some(), "Our message should be claimed");
+    assert_eq!(
+        ours.and_then(|m| m.model.as_deref()),
+        Some("test-model"),
+        "Model override should round-trip through the queue"
+    );
 
     storage.complete_message(msg_id).await.unwrap();
 
@@ -30,3 +36,44 @@ async fn pg_pending_queue_lifecycle() {
     let still_there = all.iter().any(|m| m.id == msg_id);
     assert!(!still_there, "Completed message should be deleted");
 }
+
+#[tokio::test]
+#[ignore]
+async fn pg_completed_messages_do_not_accumulate() {
+    let storage = create_pg_storage().await;
+    let session = unique_id();
+
+    let mut ids = Vec::new();
+    for call in ["call-done", "call-failed"] {
+        let id = storage
+            .queue_message(
+                &session,
+                Some(call),
+                Some("test_tool"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        ids.push(id);
+    }
+    let claimed = storage.claim_pending_messages(100, 300).await.unwrap();
+    assert!(ids.iter().all(|id| claimed.iter().any(|m| m.id == *id)));
+
+    storage.complete_message(ids[0]).await.unwrap();
+    storage.fail_message(ids[1], true).await.unwrap();
+
+    let statuses: Vec<(i64, String)> =
+        sqlx::query_as("SELECT id, status FROM pending_messages WHERE session_id = $1 ORDER BY id")
+            .bind(&session)
+            .fetch_all(&storage.pool())
+            .await
+            .unwrap();
+    assert_eq!(
+        statuses,
+        vec![(ids[1], "failed".to_owned())],
+        "completed rows are removed immediately; failed rows stay for the DLQ"
+    );
+}
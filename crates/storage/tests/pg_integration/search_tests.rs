@@ -1,4 +1,6 @@
 use super::test_fixtures::{create_pg_storage, make_observation, unique_id};
+use chrono::{Duration, Utc};
+use opencode_mem_core::Concept;
 use opencode_mem_storage::traits::{ObservationStore, SearchStore};
 
 #[tokio::test]
@@ -22,3 +24,150 @@ async fn pg_search_observations() {
         "Observation should be found via FTS search for 'xylophone'"
     );
 }
+
+#[tokio::test]
+#[ignore]
+async fn pg_blank_queries_are_uniform() {
+    let storage = create_pg_storage().await;
+    let id = unique_id();
+    let project = unique_id();
+    let obs = make_observation(&id, "pg-test-session", &project, &format!("Blank {id}"));
+    storage.save_observation(&obs).await.unwrap();
+
+    let unfiltered = storage
+        .search_with_filters(None, Some(&project), None, None, None, 10)
+        .await
+        .unwrap();
+    assert!(unfiltered.iter().any(|r| r.id.0 == id));
+
+    for blank in ["", "   ", "   \n"] {
+        assert!(
+            storage.search(blank, 10).await.unwrap().is_empty(),
+            "FTS search for {blank:?} should be empty"
+        );
+        assert!(
+            storage.hybrid_search(blank, 10).await.unwrap().is_empty(),
+            "Hybrid search for {blank:?} should be empty"
+        );
+        assert!(
+            storage
+                .hybrid_search_v2(blank, &[], 10)
+                .await
+                .unwrap()
+                .is_empty(),
+            "Hybrid v2 search for {blank:?} should be empty"
+        );
+
+        let filtered = storage
+            .search_with_filters(Some(blank), Some(&project), None, None, None, 10)
+            .await
+            .unwrap();
+        let filtered_ids: Vec<_> = filtered.iter().map(|r| r.id.0.clone()).collect();
+        let unfiltered_ids: Vec<_> = unfiltered.iter().map(|r| r.id.0.clone()).collect();
+        assert_eq!(
+            filtered_ids, unfiltered_ids,
+            "Blank query {blank:?} should behave like an absent query"
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_search_by_keyword_ranks_by_prominence() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    let keyword = format!("deadlock-{}", unique_id());
+
+    let central_id = unique_id();
+    let mut central = make_observation(
+        &central_id,
+        "pg-test-session",
+        &project,
+        &format!("Fixed {keyword} in queue worker"),
+    );
+    central.keywords = vec![keyword.clone(), "queue".to_owned()];
+
+    let incidental_id = unique_id();
+    let mut incidental = make_observation(
+        &incidental_id,
+        "pg-test-session",
+        &project,
+        &format!("Refactored worker pool {incidental_id}"),
+    );
+    incidental.keywords = vec!["pool".to_owned(), "worker".to_owned(), keyword.clone()];
+
+    let substring_id = unique_id();
+    let mut substring = make_observation(
+        &substring_id,
+        "pg-test-session",
+        &project,
+        &format!("Unrelated {substring_id}"),
+    );
+    substring.keywords = vec![format!("{keyword}-adjacent")];
+
+    for obs in [&incidental, &substring, &central] {
+        storage.save_observation(obs).await.unwrap();
+    }
+
+    let results = storage.search_by_keyword(&keyword, 10).await.unwrap();
+    let ids: Vec<_> = results.iter().map(|r| r.id.0.clone()).collect();
+    assert_eq!(
+        ids,
+        vec![central_id, incidental_id],
+        "Only exact keyword matches, most prominent first"
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_search_by_concept_filters_on_concepts() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+
+    let older_id = unique_id();
+    let mut older = make_observation(
+        &older_id,
+        "pg-test-session",
+        &project,
+        &format!("Older gotcha {older_id}"),
+    );
+    older.concepts = vec![Concept::Gotcha, Concept::HowItWorks];
+    older.created_at = Utc::now() - Duration::hours(1);
+
+    let gotcha_id = unique_id();
+    let mut gotcha = make_observation(
+        &gotcha_id,
+        "pg-test-session",
+        &project,
+        &format!("Gotcha {gotcha_id}"),
+    );
+    gotcha.concepts = vec![Concept::Gotcha];
+
+    let pattern_id = unique_id();
+    let mut pattern = make_observation(
+        &pattern_id,
+        "pg-test-session",
+        &project,
+        &format!("Mentions gotcha in title only {pattern_id}"),
+    );
+    pattern.concepts = vec![Concept::Pattern];
+
+    for obs in [&older, &gotcha, &pattern] {
+        storage.save_observation(obs).await.unwrap();
+    }
+
+    let results = storage
+        .search_by_concept(Concept::Gotcha, Some(&project), 50)
+        .await
+        .unwrap();
+    let ids: Vec<_> = results
+        .iter()
+        .map(|r| r.id.0.clone())
+        .filter(|id| [&older_id, &gotcha_id, &pattern_id].contains(&id))
+        .collect();
+    assert_eq!(
+        ids,
+        vec![gotcha_id, older_id],
+        "Only gotcha-tagged observations, newest first"
+    );
+}
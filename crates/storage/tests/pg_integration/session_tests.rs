@@ -1,6 +1,7 @@
-use super::test_fixtures::{create_pg_storage, make_session, unique_id};
-use opencode_mem_core::SessionStatus;
-use opencode_mem_storage::traits::SessionStore;
+use super::test_fixtures::{create_pg_storage, make_observation, make_session, unique_id};
+use chrono::Utc;
+use opencode_mem_core::{ContentSessionId, PromptNumber, SessionStatus, UserPrompt};
+use opencode_mem_storage::traits::{ObservationStore, PromptStore, SessionStore};
 
 #[tokio::test]
 #[ignore]
@@ -47,3 +48,90 @@ async fn pg_session_status_update() {
 
     storage.delete_session(&id).await.unwrap();
 }
+
+#[tokio::test]
+#[ignore]
+async fn pg_prompt_numbers_link_observations() {
+    let storage = create_pg_storage().await;
+    let id = unique_id();
+    let project = unique_id();
+    let session = make_session(&id, &project);
+    let content_id = format!("content-{id}");
+
+    storage.save_session(&session).await.unwrap();
+    assert_eq!(
+        storage
+            .get_current_prompt_number(&content_id)
+            .await
+            .unwrap(),
+        None
+    );
+    let make_prompt = |prompt_id: String, text: &str| {
+        UserPrompt::new(
+            prompt_id,
+            ContentSessionId::from(content_id.clone()),
+            PromptNumber(0),
+            text.to_owned(),
+            None,
+            Utc::now(),
+        )
+    };
+    let first = make_prompt(unique_id(), "First prompt");
+    assert_eq!(storage.save_next_user_prompt(&first).await.unwrap(), 1);
+    let prompt_id = unique_id();
+    let prompt = make_prompt(prompt_id.clone(), "Second prompt");
+    assert_eq!(storage.save_next_user_prompt(&prompt).await.unwrap(), 2);
+
+    // Resolvable by session ID as well as by content session ID.
+    for key in [&id, &content_id] {
+        assert_eq!(
+            storage.get_current_prompt_number(key).await.unwrap(),
+            Some(2)
+        );
+    }
+
+    let earlier = make_observation(&unique_id(), &content_id, &project, &unique_id());
+    let mut current = make_observation(&unique_id(), &content_id, &project, &unique_id());
+    current.prompt_number = Some(PromptNumber(2));
+    storage.save_observation(&earlier).await.unwrap();
+    storage.save_observation(&current).await.unwrap();
+
+    let linked = storage.get_prompt_observations(&prompt_id).await.unwrap();
+    let ids: Vec<&str> = linked.iter().map(|o| o.id.0.as_str()).collect();
+    assert_eq!(ids, vec![current.id.0.as_str()]);
+
+    assert!(
+        storage
+            .get_prompt_observations("missing-prompt")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    storage.delete_session(&id).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_duplicate_prompt_is_stored_once() {
+    let storage = create_pg_storage().await;
+    let content_id = format!("content-{}", unique_id());
+    let text = format!("Resubmitted prompt {}", unique_id());
+    let make_prompt = || {
+        UserPrompt::new(
+            unique_id(),
+            ContentSessionId::from(content_id.clone()),
+            PromptNumber(1),
+            text.clone(),
+            None,
+            Utc::now(),
+        )
+    };
+
+    assert!(storage.save_user_prompt(&make_prompt()).await.unwrap());
+    assert!(!storage.save_user_prompt(&make_prompt()).await.unwrap());
+
+    let stored = storage.search_prompts(&text, 10).await.unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].prompt_text, text);
+}
@@ -1,5 +1,7 @@
 use super::test_fixtures::{create_pg_storage, make_observation, make_session, unique_id};
+use opencode_mem_core::{NoiseLevel, Observation, ObservationSort, ObservationType, ProjectId};
 use opencode_mem_storage::traits::{ObservationStore, SessionStore, StatsStore};
+use opencode_mem_storage::{PaginatedResult, PgStorage};
 
 #[tokio::test]
 #[ignore]
@@ -29,3 +31,189 @@ async fn pg_stats() {
 
     storage.delete_session(&sess_id).await.unwrap();
 }
+
+async fn count(
+    storage: &PgStorage,
+    project: Option<&str>,
+    obs_type: Option<&str>,
+    from: &str,
+    to: &str,
+) -> usize {
+    storage
+        .count_observations(project, obs_type, Some(from), Some(to))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_count_observations_with_filters() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    // A random minute in 1990-2030 keeps every count bounded to this run's rows.
+    let offset = uuid::Uuid::new_v4().as_u128() % 21_000_000;
+    let base = chrono::DateTime::parse_from_rfc3339("1990-01-01T00:00:00Z")
+        .unwrap()
+        .to_utc()
+        + chrono::Duration::minutes(i64::try_from(offset).unwrap());
+    let at = |minutes: i64| (base + chrono::Duration::minutes(minutes)).to_rfc3339();
+
+    let other_project = unique_id();
+    let mut ids = Vec::new();
+    for (minutes, obs_type, row_project) in [
+        (0, ObservationType::Bugfix, Some(project.as_str())),
+        (5, ObservationType::Bugfix, Some(other_project.as_str())),
+        (10, ObservationType::Bugfix, Some(project.as_str())),
+        (15, ObservationType::Bugfix, None),
+        (20, ObservationType::Discovery, Some(project.as_str())),
+    ] {
+        let id = unique_id();
+        let mut obs = make_observation(&id, "pg-test-session", "", &format!("Count {id}"));
+        obs.project = row_project.map(ProjectId::from);
+        obs.observation_type = obs_type;
+        obs.created_at = base + chrono::Duration::minutes(minutes);
+        storage.save_observation(&obs).await.unwrap();
+        ids.push(id);
+    }
+
+    let (from, to) = (at(-1), at(30));
+    assert_eq!(
+        count(&storage, None, None, &from, &to).await,
+        5,
+        "whole window"
+    );
+    // Project-less rows count as global and match every project filter.
+    assert_eq!(
+        count(&storage, Some(&project), None, &from, &to).await,
+        4,
+        "project"
+    );
+    assert_eq!(
+        count(&storage, Some(&project), Some("bugfix"), &from, &to).await,
+        3,
+        "project + type"
+    );
+    assert_eq!(
+        count(&storage, None, Some("discovery"), &from, &to).await,
+        1,
+        "type"
+    );
+    assert_eq!(
+        count(&storage, Some(&project), None, &at(10), &to).await,
+        3,
+        "inclusive from"
+    );
+    assert_eq!(
+        count(&storage, Some(&project), None, &from, &at(10)).await,
+        2,
+        "inclusive to"
+    );
+    assert_eq!(
+        count(&storage, Some(&project), Some("bugfix"), &at(5), &at(15)).await,
+        2,
+        "all filters"
+    );
+
+    for id in &ids {
+        storage.delete_observation_by_id(id).await.unwrap();
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_observations_paginated_sort_orders() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    let now = chrono::Utc::now();
+
+    let mut ids = Vec::new();
+    for (age_minutes, noise) in [
+        (30, NoiseLevel::Low),
+        (20, NoiseLevel::Critical),
+        (10, NoiseLevel::Medium),
+    ] {
+        let id = unique_id();
+        let mut obs = make_observation(&id, "pg-test-session", &project, &format!("Sort {id}"));
+        obs.created_at = now - chrono::Duration::minutes(age_minutes);
+        obs.noise_level = noise;
+        storage.save_observation(&obs).await.unwrap();
+        ids.push(id);
+    }
+
+    let ordered = |page: PaginatedResult<Observation>| -> Vec<String> {
+        page.items
+            .into_iter()
+            .map(|o| o.id.to_string())
+            .filter(|id| ids.contains(id))
+            .collect()
+    };
+
+    let newest = storage
+        .get_observations_paginated(0, 100, Some(&project), ObservationSort::CreatedDesc)
+        .await
+        .unwrap();
+    assert_eq!(
+        ordered(newest),
+        vec![ids[2].clone(), ids[1].clone(), ids[0].clone()]
+    );
+
+    let oldest = storage
+        .get_observations_paginated(0, 100, Some(&project), ObservationSort::CreatedAsc)
+        .await
+        .unwrap();
+    assert_eq!(
+        ordered(oldest),
+        vec![ids[0].clone(), ids[1].clone(), ids[2].clone()]
+    );
+
+    let by_noise = storage
+        .get_observations_paginated(0, 100, Some(&project), ObservationSort::Noise)
+        .await
+        .unwrap();
+    assert_eq!(
+        ordered(by_noise),
+        vec![ids[1].clone(), ids[2].clone(), ids[0].clone()]
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn pg_project_files_aggregate_reads_and_modifications() {
+    let storage = create_pg_storage().await;
+    let project = unique_id();
+    let now = chrono::Utc::now();
+
+    for (age_minutes, read, modified) in [
+        (30, vec!["src/lib.rs"], vec!["src/main.rs"]),
+        (20, vec!["src/main.rs"], vec!["src/main.rs"]),
+        (10, vec!["Cargo.toml"], vec![]),
+    ] {
+        let id = unique_id();
+        let mut obs = make_observation(&id, "pg-test-session", &project, &format!("Files {id}"));
+        obs.created_at = now - chrono::Duration::minutes(age_minutes);
+        obs.files_read = read.into_iter().map(str::to_owned).collect();
+        obs.files_modified = modified.into_iter().map(str::to_owned).collect();
+        storage.save_observation(&obs).await.unwrap();
+    }
+    let mut other = make_observation(&unique_id(), "pg-test-session", &unique_id(), "Other");
+    other.files_modified = vec!["src/other.rs".to_owned()];
+    storage.save_observation(&other).await.unwrap();
+
+    let files = storage.get_project_files(&project, 100).await.unwrap();
+    let summary: Vec<(&str, u64, u64)> = files
+        .iter()
+        .map(|f| (f.path.as_str(), f.modified_count, f.read_count))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Cargo.toml", 0, 1),
+            ("src/main.rs", 2, 1),
+            ("src/lib.rs", 0, 1),
+        ]
+    );
+    assert!(files[0].last_touched > files[1].last_touched);
+
+    let limited = storage.get_project_files(&project, 1).await.unwrap();
+    assert_eq!(limited.len(), 1);
+}